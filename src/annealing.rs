@@ -3,47 +3,166 @@ use crate::geometry::Point;
 use crate::elements::Triangle;
 use crate::mesh::Mesh;
 use crate::delaunay::DelaunayTriangulator;
+use crate::spatial_index::BoundaryIndex;
+
+/// How a candidate vertex perturbation is generated for one annealing iteration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoveStrategy {
+    /// Isotropic random jitter scaled by temperature - the original behavior.
+    RandomJitter,
+    /// Interpolate toward the area-weighted centroid of the vertex's incident triangles
+    /// (ODT-style relaxation), blended in by a factor that decays as temperature cools.
+    AreaWeightedCentroid,
+    /// Flip a coin between the two each iteration, so annealing still escapes local minima
+    /// through occasional jitter moves while mostly smoothing toward centroids.
+    Mixed,
+}
+
+/// Which per-triangle shape metric the energy function scores triangles with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QualityMetric {
+    /// The original `min_angle / 60` times a clamped Jacobian - crude but cheap, and unstable
+    /// for thin triangles.
+    AngleJacobian,
+    /// `Triangle::radius_ratio`: scale-invariant and numerically robust on slivers.
+    RadiusRatio,
+}
+
+/// Priority-queue entry for `GeneralAnnealingOptimizer::decimate`: orders edges cheapest-first by
+/// length via `f64::total_cmp` (plain `Ord` isn't available for `f64`), so popping the max of this
+/// `BinaryHeap` yields the shortest remaining edge.
+struct EdgeCost(f64, usize, usize);
+
+impl PartialEq for EdgeCost {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for EdgeCost {}
+
+impl PartialOrd for EdgeCost {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EdgeCost {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.total_cmp(&self.0)
+    }
+}
 
 pub struct GridAnnealingMeshGenerator {
     boundary_points: Vec<Point>,
+    /// Interior obstacle/cutout loops; treated as fixed boundary vertices everywhere
+    /// `boundary_points` is, and any triangle whose centroid falls inside one is discarded.
+    holes: Vec<Vec<Point>>,
     internal_points: Vec<Point>,
     triangles: Vec<Triangle>,
     rng: rand::rngs::ThreadRng,
     quality_threshold: f64,
     temperature: f64,
     cooling_rate: f64,
+    /// Kept in sync with `boundary_points`/`holes`/`internal_points` after the initial
+    /// triangulation so `update_triangulation_after_move` can repair just the moved vertex's
+    /// star instead of rebuilding the whole mesh every annealing step.
+    triangulator: Option<DelaunayTriangulator>,
+    /// How candidate vertex perturbations are generated; see `MoveStrategy`. Defaults to the
+    /// original random-jitter behavior, set via `set_move_strategy`.
+    move_strategy: MoveStrategy,
+    /// Which per-triangle shape metric `calculate_mesh_quality` scores triangles with; see
+    /// `QualityMetric`. Defaults to the original angle/Jacobian formula.
+    quality_metric: QualityMetric,
+    /// R-tree over `boundary_points` plus `holes` flattened, rebuilt by `rebuild_boundary_index`
+    /// whenever either changes, so `is_boundary_vertex` doesn't have to rescan the whole
+    /// fixed-point set per query. `None` until a boundary has been set.
+    boundary_index: Option<BoundaryIndex>,
+    /// Snapping tolerance `is_boundary_vertex` matches vertices against, kept in sync by
+    /// `rebuild_boundary_index` so it scales with the boundary's actual extent instead of
+    /// assuming a fixed `1e-6` that silently fails on meshes in the thousands and over-matches
+    /// on sub-millimeter ones.
+    boundary_tolerance: f64,
 }
 
 impl GridAnnealingMeshGenerator {
     pub fn new(boundary_points: Vec<Point>, quality_threshold: f64) -> Self {
-        Self {
+        let mut generator = Self {
             boundary_points,
+            holes: Vec::new(),
             internal_points: Vec::new(),
             triangles: Vec::new(),
             rng: rand::thread_rng(),
             quality_threshold,
             temperature: 1000.0,
             cooling_rate: 0.995,
-        }
+            triangulator: None,
+            move_strategy: MoveStrategy::RandomJitter,
+            quality_metric: QualityMetric::AngleJacobian,
+            boundary_index: None,
+            boundary_tolerance: 1e-6_f64.sqrt(),
+        };
+        generator.rebuild_boundary_index();
+        generator
     }
-    
+
     pub fn with_options(
-        boundary_points: Vec<Point>, 
-        temperature: f64, 
-        cooling_rate: f64, 
+        boundary_points: Vec<Point>,
+        temperature: f64,
+        cooling_rate: f64,
         quality_threshold: f64
     ) -> Self {
-        Self {
+        let mut generator = Self {
             boundary_points,
+            holes: Vec::new(),
             internal_points: Vec::new(),
             triangles: Vec::new(),
             rng: rand::thread_rng(),
             quality_threshold,
             temperature,
             cooling_rate,
-        }
+            triangulator: None,
+            move_strategy: MoveStrategy::RandomJitter,
+            quality_metric: QualityMetric::AngleJacobian,
+            boundary_index: None,
+            boundary_tolerance: 1e-6_f64.sqrt(),
+        };
+        generator.rebuild_boundary_index();
+        generator
     }
-    
+
+    /// Builds a generator for a multiply-connected domain: `outer` is the outer boundary loop
+    /// and every loop in `holes` is an interior cutout that the annealed mesh must avoid.
+    pub fn with_holes(outer: Vec<Point>, holes: Vec<Vec<Point>>, quality_threshold: f64) -> Self {
+        let mut generator = Self {
+            boundary_points: outer,
+            holes,
+            internal_points: Vec::new(),
+            triangles: Vec::new(),
+            rng: rand::thread_rng(),
+            quality_threshold,
+            temperature: 1000.0,
+            cooling_rate: 0.995,
+            triangulator: None,
+            move_strategy: MoveStrategy::RandomJitter,
+            quality_metric: QualityMetric::AngleJacobian,
+            boundary_index: None,
+            boundary_tolerance: 1e-6_f64.sqrt(),
+        };
+        generator.rebuild_boundary_index();
+        generator
+    }
+
+    /// Picks how candidate vertex perturbations are generated; see `MoveStrategy`.
+    pub fn set_move_strategy(&mut self, strategy: MoveStrategy) {
+        self.move_strategy = strategy;
+    }
+
+    /// Picks which per-triangle shape metric quality scoring uses; see `QualityMetric`.
+    pub fn set_quality_metric(&mut self, metric: QualityMetric) {
+        self.quality_metric = metric;
+    }
+
     pub fn generate_mesh(&mut self, target_area: f64) -> Result<Mesh, String> {
         self.generate_mesh_with_iterations(target_area, 10000)
     }
@@ -68,20 +187,34 @@ impl GridAnnealingMeshGenerator {
     
     fn refine_boundary_points(&mut self, target_area: f64) -> Result<(), String> {
         let target_edge_length = (4.0 * target_area / 3.0_f64.sqrt()).sqrt();
+
+        self.boundary_points = Self::refine_loop(&self.boundary_points, target_edge_length);
+        self.holes = self.holes.iter()
+            .map(|hole| Self::refine_loop(hole, target_edge_length))
+            .collect();
+        self.rebuild_boundary_index();
+
+        Ok(())
+    }
+
+    /// Subdivides every edge of a closed `loop_points` polygon longer than `target_edge_length`
+    /// with evenly spaced points, used for both the outer boundary and each hole loop.
+    fn refine_loop(loop_points: &[Point], target_edge_length: f64) -> Vec<Point> {
         let mut refined_points = Vec::new();
-        
-        for i in 0..self.boundary_points.len() {
-            let next_i = (i + 1) % self.boundary_points.len();
-            let p1 = &self.boundary_points[i];
-            let p2 = &self.boundary_points[next_i];
-            
+        let n = loop_points.len();
+
+        for i in 0..n {
+            let next_i = (i + 1) % n;
+            let p1 = &loop_points[i];
+            let p2 = &loop_points[next_i];
+
             refined_points.push(p1.clone());
-            
+
             let edge_length = p1.distance_to(p2);
-            
+
             if edge_length > target_edge_length {
                 let num_subdivisions = (edge_length / target_edge_length).ceil() as usize;
-                
+
                 for j in 1..num_subdivisions {
                     let t = j as f64 / num_subdivisions as f64;
                     let new_point = Point::new(
@@ -92,9 +225,8 @@ impl GridAnnealingMeshGenerator {
                 }
             }
         }
-        
-        self.boundary_points = refined_points;
-        Ok(())
+
+        refined_points
     }
     
     fn generate_internal_grid(&mut self, target_area: f64) -> Result<(), String> {
@@ -120,22 +252,107 @@ impl GridAnnealingMeshGenerator {
     }
     
     fn create_initial_triangulation(&mut self) -> Result<(), String> {
+        let boundary_count = self.boundary_points.len();
+        let hole_counts: Vec<usize> = self.holes.iter().map(|hole| hole.len()).collect();
+
         let mut all_points = self.boundary_points.clone();
+        for hole in &self.holes {
+            all_points.extend(hole.iter().cloned());
+        }
         all_points.extend(self.internal_points.clone());
-        
+
         let mut triangulator = DelaunayTriangulator::new(all_points);
-        let mesh = triangulator.triangulate()?;
-        
-        self.triangles = mesh.triangle_indices.iter().map(|&vertices| {
-            Triangle::new(vertices, &mesh.vertices)
-        }).collect();
-        
-        let boundary_count = self.boundary_points.len();
+        let mesh = if triangulator.points.len() >= DelaunayTriangulator::BULK_LOAD_THRESHOLD {
+            triangulator.triangulate_bulk_load()?
+        } else {
+            triangulator.triangulate()?
+        };
+
         self.boundary_points = mesh.vertices[..boundary_count].to_vec();
-        self.internal_points = mesh.vertices[boundary_count..].to_vec();
-        
+        let mut offset = boundary_count;
+        for (hole, &count) in self.holes.iter_mut().zip(hole_counts.iter()) {
+            *hole = mesh.vertices[offset..offset + count].to_vec();
+            offset += count;
+        }
+        self.internal_points = mesh.vertices[offset..].to_vec();
+        self.rebuild_boundary_index();
+
+        self.triangles = Self::filter_triangles(
+            &self.boundary_points,
+            &self.holes,
+            mesh.triangle_indices.iter().map(|&vertices| Triangle::new(vertices, &mesh.vertices)).collect(),
+            &mesh.vertices,
+        );
+        self.triangulator = Some(triangulator);
+
         Ok(())
     }
+
+    /// The annealed point at flattened vertex index `idx`, where the full point
+    /// set is laid out as `boundary_points ++ holes (flattened) ++ internal_points` - the same
+    /// order `create_initial_triangulation` feeds into `DelaunayTriangulator`.
+    fn point_at(&self, idx: usize) -> Point {
+        if idx < self.boundary_points.len() {
+            return self.boundary_points[idx];
+        }
+
+        let mut remaining = idx - self.boundary_points.len();
+        for hole in &self.holes {
+            if remaining < hole.len() {
+                return hole[remaining];
+            }
+            remaining -= hole.len();
+        }
+
+        self.internal_points[remaining]
+    }
+
+    /// Area-weighted centroid of the triangles incident to flattened vertex `vertex_idx` (the
+    /// polygon-gravity-center construction: each sub-triangle's centroid weighted by its own
+    /// area). `None` if the vertex has no incident triangles, e.g. it was just displaced off
+    /// every one of them.
+    fn area_weighted_centroid(&self, vertex_idx: usize) -> Option<Point> {
+        let mut weighted_x = 0.0;
+        let mut weighted_y = 0.0;
+        let mut total_area = 0.0;
+
+        for triangle in &self.triangles {
+            if !triangle.vertices.contains(&vertex_idx) {
+                continue;
+            }
+
+            let [a, b, c] = triangle.vertices;
+            let (pa, pb, pc) = (self.point_at(a), self.point_at(b), self.point_at(c));
+            let area = ((pb.x - pa.x) * (pc.y - pa.y) - (pc.x - pa.x) * (pb.y - pa.y)).abs() / 2.0;
+
+            weighted_x += area * (pa.x + pb.x + pc.x) / 3.0;
+            weighted_y += area * (pa.y + pb.y + pc.y) / 3.0;
+            total_area += area;
+        }
+
+        if total_area < 1e-12 {
+            return None;
+        }
+
+        Some(Point::new(weighted_x / total_area, weighted_y / total_area))
+    }
+
+    /// Discards every triangle whose centroid falls outside `boundary` or inside any of `holes`,
+    /// so the stored mesh only covers the (possibly multiply-connected) domain interior.
+    fn filter_triangles(boundary: &[Point], holes: &[Vec<Point>], triangles: Vec<Triangle>, points: &[Point]) -> Vec<Triangle> {
+        let mut loops: Vec<&[Point]> = vec![boundary];
+        loops.extend(holes.iter().map(Vec::as_slice));
+
+        triangles.into_iter()
+            .filter(|triangle| {
+                let centroid = Point::new(
+                    (points[triangle.vertices[0]].x + points[triangle.vertices[1]].x + points[triangle.vertices[2]].x) / 3.0,
+                    (points[triangle.vertices[0]].y + points[triangle.vertices[1]].y + points[triangle.vertices[2]].y) / 3.0,
+                );
+                crate::geometry::winding_number_inside(&loops, &centroid)
+            })
+            .collect()
+    }
     
     fn optimize_with_annealing(&mut self, max_iterations: u32) -> Result<(), String> {
         let mut iterations = 0;
@@ -154,30 +371,57 @@ impl GridAnnealingMeshGenerator {
             if !self.internal_points.is_empty() {
                 let point_idx = self.rng.gen_range(0..self.internal_points.len());
                 let old_point = self.internal_points[point_idx].clone();
-                
-                let perturbation_radius = temperature * 0.1;
-                let dx = self.rng.gen_range(-perturbation_radius..perturbation_radius);
-                let dy = self.rng.gen_range(-perturbation_radius..perturbation_radius);
-                
-                let new_point = Point::new(old_point.x + dx, old_point.y + dy);
-                
+                let hole_total: usize = self.holes.iter().map(|hole| hole.len()).sum();
+                let vertex_idx = point_idx + self.boundary_points.len() + hole_total;
+
+                let use_centroid = match self.move_strategy {
+                    MoveStrategy::RandomJitter => false,
+                    MoveStrategy::AreaWeightedCentroid => true,
+                    MoveStrategy::Mixed => self.rng.gen::<bool>(),
+                };
+
+                let new_point = if use_centroid {
+                    match self.area_weighted_centroid(vertex_idx) {
+                        // The pull toward the centroid weakens as the mesh cools, so late
+                        // low-temperature iterations take ever smaller smoothing steps.
+                        Some(centroid) => {
+                            let smoothing_factor = 0.5 * (temperature / self.temperature).min(1.0);
+                            Point::new(
+                                old_point.x + smoothing_factor * (centroid.x - old_point.x),
+                                old_point.y + smoothing_factor * (centroid.y - old_point.y),
+                            )
+                        }
+                        None => old_point,
+                    }
+                } else {
+                    let perturbation_radius = temperature * 0.1;
+                    let dx = self.rng.gen_range(-perturbation_radius..perturbation_radius);
+                    let dy = self.rng.gen_range(-perturbation_radius..perturbation_radius);
+                    Point::new(old_point.x + dx, old_point.y + dy)
+                };
+
                 if self.is_point_inside_polygon(&new_point) {
                     self.internal_points[point_idx] = new_point;
-                    
-                    self.update_triangulation_after_move(point_idx + self.boundary_points.len())?;
-                    
-                    let new_quality = self.calculate_mesh_quality();
-                    let quality_improvement = new_quality - current_quality;
-                    
-                    if quality_improvement > 0.0 || 
-                       self.rng.gen::<f64>() < (quality_improvement / temperature).exp() {
-                        if iterations % 1000 == 0 {
-                            log::info!("ANNEALING - Iteration {}: quality={:.4}, temp={:.2}", 
-                                      iterations, new_quality, temperature);
-                        }
-                    } else {
+
+                    if self.update_triangulation_after_move(vertex_idx).is_err() {
+                        // Vertex has no closed star to repair incrementally (e.g. it drifted
+                        // onto the hull) - reject the move rather than aborting annealing. The
+                        // triangulator itself is untouched on this path, so just undo the point.
                         self.internal_points[point_idx] = old_point;
-                        self.update_triangulation_after_move(point_idx + self.boundary_points.len())?;
+                    } else {
+                        let new_quality = self.calculate_mesh_quality();
+                        let quality_improvement = new_quality - current_quality;
+
+                        if quality_improvement > 0.0 ||
+                           self.rng.gen::<f64>() < (quality_improvement / temperature).exp() {
+                            if iterations % 1000 == 0 {
+                                log::info!("ANNEALING - Iteration {}: quality={:.4}, temp={:.2}",
+                                          iterations, new_quality, temperature);
+                            }
+                        } else {
+                            self.internal_points[point_idx] = old_point;
+                            let _ = self.update_triangulation_after_move(vertex_idx);
+                        }
                     }
                 }
             }
@@ -194,27 +438,40 @@ impl GridAnnealingMeshGenerator {
         if self.triangles.is_empty() {
             return 0.0;
         }
-        
+
+        // `self.triangles` indexes the full boundary+holes+internal layout (see `point_at`),
+        // so the lookup table has to cover all three, not just boundary+internal.
         let all_points: Vec<Point> = self.boundary_points.iter()
+            .chain(self.holes.iter().flatten())
             .chain(self.internal_points.iter())
             .cloned()
             .collect();
-        
+
         let mut total_quality = 0.0;
         let mut valid_triangles = 0;
-        
+
         for triangle in &self.triangles {
-            let min_angle = triangle.min_angle(&all_points);
-            let jacobian = triangle.jacobian(&all_points);
-            
-            if jacobian > 0.0 {
-                let angle_quality = min_angle / 60.0;
-                let jacobian_quality = jacobian.min(1.0);
-                total_quality += angle_quality * jacobian_quality;
-                valid_triangles += 1;
+            match self.quality_metric {
+                QualityMetric::AngleJacobian => {
+                    let min_angle = triangle.min_angle(&all_points);
+                    let jacobian = triangle.jacobian(&all_points);
+
+                    if jacobian > 0.0 {
+                        let angle_quality = min_angle / 60.0;
+                        let jacobian_quality = jacobian.min(1.0);
+                        total_quality += angle_quality * jacobian_quality;
+                        valid_triangles += 1;
+                    }
+                }
+                QualityMetric::RadiusRatio => {
+                    if triangle.jacobian(&all_points) > 0.0 {
+                        total_quality += triangle.radius_ratio(&all_points);
+                        valid_triangles += 1;
+                    }
+                }
             }
         }
-        
+
         if valid_triangles > 0 {
             total_quality / valid_triangles as f64
         } else {
@@ -222,17 +479,19 @@ impl GridAnnealingMeshGenerator {
         }
     }
     
-    fn update_triangulation_after_move(&mut self, _moved_point_idx: usize) -> Result<(), String> {
-        let mut all_points = self.boundary_points.clone();
-        all_points.extend(self.internal_points.clone());
-        
-        let mut triangulator = DelaunayTriangulator::new(all_points);
-        let mesh = triangulator.triangulate()?;
-        
-        self.triangles = mesh.triangle_indices.iter().map(|&vertices| {
-            Triangle::new(vertices, &mesh.vertices)
-        }).collect();
-        
+    fn update_triangulation_after_move(&mut self, moved_point_idx: usize) -> Result<(), String> {
+        let new_point = self.point_at(moved_point_idx);
+        let boundary = self.boundary_points.clone();
+        let holes = self.holes.clone();
+
+        let triangulator = match self.triangulator.as_mut() {
+            Some(t) => t,
+            None => return Ok(()),
+        };
+
+        triangulator.move_vertex(moved_point_idx, new_point)?;
+        self.triangles = Self::filter_triangles(&boundary, &holes, triangulator.live_triangles(), &triangulator.points);
+
         Ok(())
     }
     
@@ -287,41 +546,42 @@ impl GridAnnealingMeshGenerator {
         (min_x, max_x, min_y, max_y)
     }
     
+    /// True when `point` is inside the outer boundary and outside every hole loop.
     fn is_point_inside_polygon(&self, point: &Point) -> bool {
-        let mut inside = false;
-        let boundary_count = self.boundary_points.len();
-        let mut j = boundary_count - 1;
+        let mut loops: Vec<&[Point]> = vec![&self.boundary_points];
+        loops.extend(self.holes.iter().map(Vec::as_slice));
+        crate::geometry::winding_number_inside(&loops, point)
+    }
 
-        for i in 0..boundary_count {
-            let pi = &self.boundary_points[i];
-            let pj = &self.boundary_points[j];
-            
-            if ((pi.y > point.y) != (pj.y > point.y)) &&
-               (point.x < (pj.x - pi.x) * (point.y - pi.y) / (pj.y - pi.y) + pi.x) {
-                inside = !inside;
-            }
-            j = i;
+    /// Rebuilds `boundary_index` from the current `boundary_points`/`holes`; called whenever
+    /// either changes so the index never answers queries against a stale fixed-point set. Also
+    /// refreshes `boundary_tolerance` from the same fixed-point set's bounding box.
+    fn rebuild_boundary_index(&mut self) {
+        let fixed_points: Vec<Point> = self.boundary_points.iter()
+            .chain(self.holes.iter().flatten())
+            .cloned()
+            .collect();
+
+        if !fixed_points.is_empty() {
+            self.boundary_tolerance = crate::spatial_index::default_snap_tolerance(&fixed_points);
         }
-        
-        inside
+
+        self.boundary_index = if fixed_points.is_empty() {
+            None
+        } else {
+            Some(BoundaryIndex::build(fixed_points))
+        };
     }
 
     fn is_boundary_vertex(&self, vertex: &Point) -> bool {
         if self.boundary_points.is_empty() {
             return false;
         }
-        
-        let tolerance = 1e-6;
-        
-        // Check if this vertex is very close to any boundary point
-        for boundary_point in &self.boundary_points {
-            let distance_sq = (vertex.x - boundary_point.x).powi(2) + (vertex.y - boundary_point.y).powi(2);
-            if distance_sq < tolerance {
-                return true;
-            }
+
+        match &self.boundary_index {
+            Some(index) => index.nearest_within(vertex, self.boundary_tolerance),
+            None => false,
         }
-        
-        false
     }
 }
 
@@ -336,10 +596,39 @@ pub struct GeneralAnnealingOptimizer {
     pub aspect_ratio_weight: f64,
     pub check_size_uniformity: bool,
     pub size_uniformity_weight: f64,
+    /// Whether `calculate_enhanced_quality` folds in `Triangle::radius_ratio` as its own blend
+    /// term, independent of `quality_metric`'s choice for `aspect_ratio_weight`.
+    pub check_radius_ratio: bool,
+    pub radius_ratio_weight: f64,
     pub target_area: f64,
     pub min_area: f64,
     pub boundary_points: Vec<Point>, // Store original boundary for validation
+    /// Interior cutout loops the annealed mesh must avoid, set via `set_holes`.
+    holes: Vec<Vec<Point>>,
     rng: rand::rngs::ThreadRng,
+    /// Built at the start of `optimize_mesh` and kept in sync by `move_vertex` so each accepted
+    /// or rejected perturbation repairs only the moved vertex's star instead of retriangulating
+    /// the whole mesh.
+    triangulator: Option<DelaunayTriangulator>,
+    /// How candidate vertex perturbations are generated; see `MoveStrategy`. Defaults to the
+    /// original random-jitter behavior, set via `set_move_strategy`.
+    move_strategy: MoveStrategy,
+    /// Which per-triangle shape metric the energy function scores triangles with; see
+    /// `QualityMetric`. `target_aspect_ratio`/`aspect_ratio_weight` are interpreted against
+    /// whichever metric this selects.
+    quality_metric: QualityMetric,
+    /// R-tree over `boundary_points` plus `holes` flattened, rebuilt by `rebuild_boundary_index`
+    /// whenever either changes, so `is_boundary_vertex`/`count_boundary_vertices` don't have to
+    /// rescan the whole fixed-point set per query. `None` until a boundary has been set.
+    boundary_index: Option<BoundaryIndex>,
+    /// Explicit override for `boundary_tolerance`, set via `set_boundary_tolerance`. `None` means
+    /// keep deriving it from the boundary's bounding box.
+    boundary_tolerance_override: Option<f64>,
+    /// Snapping tolerance `is_boundary_vertex`/`count_boundary_vertices` match vertices against,
+    /// kept in sync by `rebuild_boundary_index` so it scales with the boundary's actual extent
+    /// instead of assuming a fixed `1e-6` that silently fails on meshes in the thousands and
+    /// over-matches on sub-millimeter ones.
+    boundary_tolerance: f64,
 }
 
 impl GeneralAnnealingOptimizer {
@@ -350,15 +639,24 @@ impl GeneralAnnealingOptimizer {
             max_iterations: 10000,
             check_volume: true,
             check_aspect_ratio: true,
-            target_aspect_ratio: 1.73, // Ideal equilateral triangle ratio
+            target_aspect_ratio: 1.0, // Radius ratio of an equilateral triangle
             volume_weight: 0.3,
             aspect_ratio_weight: 0.4,
             check_size_uniformity: true,
             size_uniformity_weight: 0.3,
+            check_radius_ratio: false,
+            radius_ratio_weight: 0.3,
             target_area: 0.1,
             min_area: 0.01,
             boundary_points: Vec::new(),
+            holes: Vec::new(),
             rng: rand::thread_rng(),
+            triangulator: None,
+            move_strategy: MoveStrategy::RandomJitter,
+            quality_metric: QualityMetric::AngleJacobian,
+            boundary_index: None,
+            boundary_tolerance_override: None,
+            boundary_tolerance: 1e-6_f64.sqrt(),
         }
     }
 
@@ -369,20 +667,76 @@ impl GeneralAnnealingOptimizer {
             max_iterations: options.max_iterations.unwrap_or(10000),
             check_volume: options.check_volume.unwrap_or(true),
             check_aspect_ratio: options.check_aspect_ratio.unwrap_or(true),
-            target_aspect_ratio: options.target_aspect_ratio.unwrap_or(1.73),
+            target_aspect_ratio: options.target_aspect_ratio.unwrap_or(1.0),
             volume_weight: options.volume_weight.unwrap_or(0.3),
             aspect_ratio_weight: options.aspect_ratio_weight.unwrap_or(0.4),
             check_size_uniformity: options.check_size_uniformity.unwrap_or(true),
             size_uniformity_weight: options.size_uniformity_weight.unwrap_or(0.3),
+            check_radius_ratio: options.check_radius_ratio.unwrap_or(false),
+            radius_ratio_weight: options.radius_ratio_weight.unwrap_or(0.3),
             target_area: options.target_area.unwrap_or(0.1),
             min_area: options.min_area.unwrap_or(0.01),
             boundary_points: Vec::new(),
+            holes: Vec::new(),
             rng: rand::thread_rng(),
+            triangulator: None,
+            move_strategy: options.move_strategy.unwrap_or(MoveStrategy::RandomJitter),
+            quality_metric: options.quality_metric.unwrap_or(QualityMetric::AngleJacobian),
+            boundary_index: None,
+            boundary_tolerance_override: None,
+            boundary_tolerance: 1e-6_f64.sqrt(),
         }
     }
 
     pub fn set_boundary(&mut self, boundary_points: Vec<Point>) {
         self.boundary_points = boundary_points;
+        self.rebuild_boundary_index();
+    }
+
+    /// Registers interior cutout loops that `is_point_inside_boundary`/`is_boundary_vertex` must
+    /// treat as off-limits, mirroring `GridAnnealingMeshGenerator::with_holes`.
+    pub fn set_holes(&mut self, holes: Vec<Vec<Point>>) {
+        self.holes = holes;
+        self.rebuild_boundary_index();
+    }
+
+    /// Overrides the scale-derived `boundary_tolerance` with an explicit value, for callers who
+    /// know their own snapping distance better than the bounding-box heuristic can guess it.
+    pub fn set_boundary_tolerance(&mut self, tolerance: f64) {
+        self.boundary_tolerance_override = Some(tolerance);
+        self.boundary_tolerance = tolerance;
+    }
+
+    /// Rebuilds `boundary_index` from the current `boundary_points`/`holes`; called whenever
+    /// either changes so the index never answers queries against a stale fixed-point set. Also
+    /// refreshes `boundary_tolerance` from the same fixed-point set's bounding box, unless the
+    /// caller pinned one via `set_boundary_tolerance`.
+    fn rebuild_boundary_index(&mut self) {
+        let fixed_points: Vec<Point> = self.boundary_points.iter()
+            .chain(self.holes.iter().flatten())
+            .cloned()
+            .collect();
+        if let Some(override_tolerance) = self.boundary_tolerance_override {
+            self.boundary_tolerance = override_tolerance;
+        } else if !fixed_points.is_empty() {
+            self.boundary_tolerance = crate::spatial_index::default_snap_tolerance(&fixed_points);
+        }
+
+        self.boundary_index = if fixed_points.is_empty() {
+            None
+        } else {
+            Some(BoundaryIndex::build(fixed_points))
+        };
+    }
+
+    /// Picks how candidate vertex perturbations are generated; see `MoveStrategy`.
+    pub fn set_move_strategy(&mut self, strategy: MoveStrategy) {
+        self.move_strategy = strategy;
+    }
+
+    /// Picks which per-triangle shape metric quality scoring uses; see `QualityMetric`.
+    pub fn set_quality_metric(&mut self, metric: QualityMetric) {
+        self.quality_metric = metric;
     }
 
     pub fn optimize_mesh(&mut self, mesh: &mut Mesh) -> Result<(), String> {
@@ -402,9 +756,25 @@ impl GeneralAnnealingOptimizer {
         
         let mut temperature = self.temperature;
         let mut iterations = 0;
-        
+
         let boundary_count = self.count_boundary_vertices(&mesh.vertices);
-        
+
+        let mut triangulator = DelaunayTriangulator::new(mesh.vertices.clone());
+        if triangulator.points.len() >= DelaunayTriangulator::BULK_LOAD_THRESHOLD {
+            triangulator.triangulate_bulk_load()?;
+        } else {
+            triangulator.triangulate()?;
+        }
+        mesh.triangle_indices = triangulator.live_triangles().iter().map(|t| t.vertices).collect();
+        mesh.triangles = mesh.triangle_indices.iter()
+            .map(|v| vec![
+                mesh.vertices[v[0]].clone(),
+                mesh.vertices[v[1]].clone(),
+                mesh.vertices[v[2]].clone(),
+            ])
+            .collect();
+        self.triangulator = Some(triangulator);
+
         while iterations < self.max_iterations && temperature > 0.1 {
             let current_quality = self.calculate_enhanced_quality(mesh);
             
@@ -412,30 +782,55 @@ impl GeneralAnnealingOptimizer {
                 let vertex_idx = self.rng.gen_range(boundary_count..mesh.vertices.len());
                 let old_vertex = mesh.vertices[vertex_idx].clone();
                 
-                let perturbation_radius = temperature * 0.05;
-                let dx = self.rng.gen_range(-perturbation_radius..perturbation_radius);
-                let dy = self.rng.gen_range(-perturbation_radius..perturbation_radius);
-                
-                let new_vertex = Point::new(old_vertex.x + dx, old_vertex.y + dy);
-                
+                let use_centroid = match self.move_strategy {
+                    MoveStrategy::RandomJitter => false,
+                    MoveStrategy::AreaWeightedCentroid => true,
+                    MoveStrategy::Mixed => self.rng.gen::<bool>(),
+                };
+
+                let new_vertex = if use_centroid {
+                    match Self::area_weighted_centroid_for_mesh(mesh, vertex_idx) {
+                        // The pull toward the centroid weakens as the mesh cools, so late
+                        // low-temperature iterations take ever smaller smoothing steps.
+                        Some(centroid) => {
+                            let smoothing_factor = 0.5 * (temperature / self.temperature).min(1.0);
+                            Point::new(
+                                old_vertex.x + smoothing_factor * (centroid.x - old_vertex.x),
+                                old_vertex.y + smoothing_factor * (centroid.y - old_vertex.y),
+                            )
+                        }
+                        None => old_vertex,
+                    }
+                } else {
+                    let perturbation_radius = temperature * 0.05;
+                    let dx = self.rng.gen_range(-perturbation_radius..perturbation_radius);
+                    let dy = self.rng.gen_range(-perturbation_radius..perturbation_radius);
+                    Point::new(old_vertex.x + dx, old_vertex.y + dy)
+                };
+
                 // Only move the vertex if it stays inside the boundary and is not a boundary vertex
                 if self.is_point_inside_boundary(&new_vertex) && !self.is_boundary_vertex(&old_vertex) {
                     mesh.vertices[vertex_idx] = new_vertex;
-                    
-                    self.update_triangles_after_vertex_move(mesh, vertex_idx);
-                    
-                    let new_quality = self.calculate_enhanced_quality(mesh);
-                    let quality_improvement = new_quality - current_quality;
-                    
-                    if quality_improvement > 0.0 || 
-                       self.rng.gen::<f64>() < (quality_improvement / temperature).exp() {
-                        if iterations % 1000 == 0 {
-                            log::info!("GENERAL ANNEALING - Iteration {}: quality={:.4}, temp={:.2}", 
-                                      iterations, new_quality, temperature);
-                        }
-                    } else {
+
+                    if self.update_triangles_after_vertex_move(mesh, vertex_idx).is_err() {
+                        // Vertex has no closed star to repair incrementally (e.g. it's on the
+                        // hull) - reject the move rather than aborting the optimization. The
+                        // triangulator itself is untouched on this path, so just undo the point.
                         mesh.vertices[vertex_idx] = old_vertex;
-                        self.update_triangles_after_vertex_move(mesh, vertex_idx);
+                    } else {
+                        let new_quality = self.calculate_enhanced_quality(mesh);
+                        let quality_improvement = new_quality - current_quality;
+
+                        if quality_improvement > 0.0 ||
+                           self.rng.gen::<f64>() < (quality_improvement / temperature).exp() {
+                            if iterations % 1000 == 0 {
+                                log::info!("GENERAL ANNEALING - Iteration {}: quality={:.4}, temp={:.2}",
+                                          iterations, new_quality, temperature);
+                            }
+                        } else {
+                            mesh.vertices[vertex_idx] = old_vertex;
+                            let _ = self.update_triangles_after_vertex_move(mesh, vertex_idx);
+                        }
                     }
                 } else {
                     // Skip this iteration if the move would be outside the boundary
@@ -504,7 +899,7 @@ impl GeneralAnnealingOptimizer {
         mesh.triangle_indices.iter().enumerate()
             .filter_map(|(i, &vertices)| {
                 let triangle = Triangle::new(vertices, &mesh.vertices);
-                let area = triangle.volume(&mesh.vertices);
+                let area = triangle.area(&mesh.vertices);
                 if area > max_allowed_area {
                     Some(i)
                 } else {
@@ -518,7 +913,7 @@ impl GeneralAnnealingOptimizer {
         mesh.triangle_indices.iter().enumerate()
             .filter_map(|(i, &vertices)| {
                 let triangle = Triangle::new(vertices, &mesh.vertices);
-                let area = triangle.volume(&mesh.vertices);
+                let area = triangle.area(&mesh.vertices);
                 if area < self.min_area {
                     Some(i)
                 } else {
@@ -607,19 +1002,229 @@ impl GeneralAnnealingOptimizer {
     fn retriangulate_mesh(&self, mesh: &mut Mesh) -> Result<(), String> {
         // Use Delaunay triangulation to rebuild the mesh with all vertices
         let mut triangulator = DelaunayTriangulator::new(mesh.vertices.clone());
-        let new_mesh = triangulator.triangulate()?;
+        let new_mesh = if triangulator.points.len() >= DelaunayTriangulator::BULK_LOAD_THRESHOLD {
+            triangulator.triangulate_bulk_load()?
+        } else {
+            triangulator.triangulate()?
+        };
         
         // Update the mesh
         mesh.vertices = new_mesh.vertices;
         mesh.triangle_indices = new_mesh.triangle_indices;
         mesh.triangles = new_mesh.triangles;
         
-        log::info!("ADAPTIVE REFINEMENT - Retriangulated mesh: {} vertices, {} triangles", 
+        log::info!("ADAPTIVE REFINEMENT - Retriangulated mesh: {} vertices, {} triangles",
                   mesh.vertices.len(), mesh.triangles.len());
-        
+
         Ok(())
     }
 
+    /// Post-pass over an already-triangulated `mesh` that greedily flips non-Delaunay interior
+    /// edges to improve connectivity (the quality energy rewards large `min_angle`, but nothing
+    /// upstream of this actively improves it). Builds an edge -> (tri_a, tri_b) adjacency map
+    /// keyed by sorted vertex-index pairs, then repeatedly pops an edge, in-circle tests it
+    /// against the apex vertex of the opposite triangle, and flips the diagonal when that test
+    /// fails - pushing the four edges of the two new triangles back onto the queue so a flip can
+    /// cascade. Boundary edges (both endpoints on the boundary/holes) are never flipped, and a
+    /// flip that would produce a non-positively-oriented triangle is skipped even if the
+    /// in-circle test calls for it.
+    pub fn optimize_edge_flips(&self, mesh: &mut Mesh, max_passes: usize) {
+        let mut adjacency: std::collections::HashMap<(usize, usize), Vec<usize>> = std::collections::HashMap::new();
+        for (tri_idx, verts) in mesh.triangle_indices.iter().enumerate() {
+            for &(a, b) in &[(verts[0], verts[1]), (verts[1], verts[2]), (verts[2], verts[0])] {
+                let key = if a < b { (a, b) } else { (b, a) };
+                adjacency.entry(key).or_insert_with(Vec::new).push(tri_idx);
+            }
+        }
+
+        let mut queue: std::collections::VecDeque<(usize, usize)> = adjacency.keys().cloned().collect();
+        let mut passes = 0;
+
+        while let Some((a, b)) = queue.pop_front() {
+            passes += 1;
+            if passes > max_passes {
+                break;
+            }
+
+            let shared = match adjacency.get(&(a, b)) {
+                Some(tris) if tris.len() == 2 => [tris[0], tris[1]],
+                _ => continue,
+            };
+
+            let tri_a = mesh.triangle_indices[shared[0]];
+            let tri_b = mesh.triangle_indices[shared[1]];
+            let apex_a = match tri_a.iter().find(|&&v| v != a && v != b) {
+                Some(&v) => v,
+                None => continue,
+            };
+            let apex_b = match tri_b.iter().find(|&&v| v != a && v != b) {
+                Some(&v) => v,
+                None => continue,
+            };
+
+            if self.is_boundary_vertex(&mesh.vertices[a]) && self.is_boundary_vertex(&mesh.vertices[b]) {
+                continue;
+            }
+
+            let triangle_a = Triangle::new(tri_a, &mesh.vertices);
+            if !triangle_a.contains_point_in_circumcircle(&mesh.vertices[apex_b]) {
+                continue; // already Delaunay for this edge
+            }
+
+            let flipped_a = [apex_a, apex_b, a];
+            let flipped_b = [apex_b, apex_a, b];
+            if Triangle::new(flipped_a, &mesh.vertices).jacobian(&mesh.vertices) <= 0.0
+                || Triangle::new(flipped_b, &mesh.vertices).jacobian(&mesh.vertices) <= 0.0 {
+                continue;
+            }
+
+            for verts in [tri_a, tri_b] {
+                for &(x, y) in &[(verts[0], verts[1]), (verts[1], verts[2]), (verts[2], verts[0])] {
+                    let key = if x < y { (x, y) } else { (y, x) };
+                    if let Some(tris) = adjacency.get_mut(&key) {
+                        tris.retain(|&t| t != shared[0] && t != shared[1]);
+                    }
+                }
+            }
+
+            mesh.triangle_indices[shared[0]] = flipped_a;
+            mesh.triangle_indices[shared[1]] = flipped_b;
+
+            let new_diagonal = if apex_a < apex_b { (apex_a, apex_b) } else { (apex_b, apex_a) };
+            adjacency.entry(new_diagonal).or_insert_with(Vec::new).extend([shared[0], shared[1]]);
+
+            // The four edges surrounding the flipped quad, each now bordering only one of the
+            // two new triangles; re-queue them so a flip here can cascade outward.
+            let surrounding = [
+                (a, apex_a, shared[0]),
+                (apex_a, b, shared[1]),
+                (b, apex_b, shared[1]),
+                (apex_b, a, shared[0]),
+            ];
+            for (x, y, tri_idx) in surrounding {
+                let key = if x < y { (x, y) } else { (y, x) };
+                adjacency.entry(key).or_insert_with(Vec::new).push(tri_idx);
+                queue.push_back(key);
+            }
+        }
+
+        mesh.triangles = mesh.triangle_indices.iter()
+            .map(|v| vec![mesh.vertices[v[0]], mesh.vertices[v[1]], mesh.vertices[v[2]]])
+            .collect();
+    }
+
+    /// Reduces `mesh` to roughly `target_count` triangles via half-edge collapse, prioritizing
+    /// the shortest collapsible edge first (mirroring OpenMesh's edge-length/aspect-ratio
+    /// decimater modules). For each candidate edge, previews the one-ring that would result from
+    /// merging its second endpoint onto its first: the collapse is rejected if any resulting
+    /// triangle would have a non-positive `jacobian`, a `radius_ratio` more than `aspect_ratio_factor`
+    /// times worse than `target_aspect_ratio`, or a `min_angle` below `min_angle_floor`. Never
+    /// collapses an edge with a boundary endpoint (`is_boundary_vertex`), so the domain outline is
+    /// preserved, and stops once `target_count` is reached or no legal collapse remains. Returns
+    /// `(quality_before, quality_after)` from `calculate_enhanced_quality`.
+    pub fn decimate(&self, mesh: &mut Mesh, target_count: usize, aspect_ratio_factor: f64, min_angle_floor: f64) -> (f64, f64) {
+        let quality_before = self.calculate_enhanced_quality(mesh);
+
+        let mut active: Vec<bool> = vec![true; mesh.vertices.len()];
+        let mut removed_triangles: Vec<bool> = vec![false; mesh.triangle_indices.len()];
+        let mut live_count = mesh.triangle_indices.len();
+
+        let mut queue: std::collections::BinaryHeap<EdgeCost> = std::collections::BinaryHeap::new();
+        let mut queued_edges: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+        let mut enqueue = |queue: &mut std::collections::BinaryHeap<EdgeCost>,
+                           queued_edges: &mut std::collections::HashSet<(usize, usize)>,
+                           x: usize, y: usize, vertices: &[Point]| {
+            let key = if x < y { (x, y) } else { (y, x) };
+            if queued_edges.insert(key) {
+                queue.push(EdgeCost(vertices[key.0].distance_to(&vertices[key.1]), key.0, key.1));
+            }
+        };
+
+        for verts in &mesh.triangle_indices {
+            for &(x, y) in &[(verts[0], verts[1]), (verts[1], verts[2]), (verts[2], verts[0])] {
+                enqueue(&mut queue, &mut queued_edges, x, y, &mesh.vertices);
+            }
+        }
+
+        while live_count > target_count {
+            let EdgeCost(_, a, b) = match queue.pop() {
+                Some(edge) => edge,
+                None => break, // no legal collapse remains
+            };
+            queued_edges.remove(&(a, b));
+
+            if !active[a] || !active[b] {
+                continue; // stale entry left behind by an earlier collapse
+            }
+            if self.is_boundary_vertex(&mesh.vertices[a]) || self.is_boundary_vertex(&mesh.vertices[b]) {
+                continue;
+            }
+
+            // Preview the one-ring of `b` with `b` merged onto `a`; triangles straddling the
+            // collapsed edge itself (referencing both `a` and `b`) simply disappear.
+            let mut preview_ok = true;
+            for (tri_idx, verts) in mesh.triangle_indices.iter().enumerate() {
+                if removed_triangles[tri_idx] || !verts.contains(&b) || verts.contains(&a) {
+                    continue;
+                }
+                let new_verts = [
+                    if verts[0] == b { a } else { verts[0] },
+                    if verts[1] == b { a } else { verts[1] },
+                    if verts[2] == b { a } else { verts[2] },
+                ];
+                let triangle = Triangle::new(new_verts, &mesh.vertices);
+                if triangle.jacobian(&mesh.vertices) <= 0.0
+                    || triangle.radius_ratio(&mesh.vertices) < self.target_aspect_ratio / aspect_ratio_factor
+                    || triangle.min_angle(&mesh.vertices) < min_angle_floor {
+                    preview_ok = false;
+                    break;
+                }
+            }
+
+            if !preview_ok {
+                continue;
+            }
+
+            for (tri_idx, verts) in mesh.triangle_indices.iter_mut().enumerate() {
+                if removed_triangles[tri_idx] || !verts.contains(&b) {
+                    continue;
+                }
+                if verts.contains(&a) {
+                    removed_triangles[tri_idx] = true;
+                    live_count -= 1;
+                } else {
+                    for v in verts.iter_mut() {
+                        if *v == b {
+                            *v = a;
+                        }
+                    }
+                }
+            }
+            active[b] = false;
+
+            let a_ring: Vec<[usize; 3]> = mesh.triangle_indices.iter().enumerate()
+                .filter(|(tri_idx, verts)| !removed_triangles[*tri_idx] && verts.contains(&a))
+                .map(|(_, verts)| *verts)
+                .collect();
+            for verts in a_ring {
+                for &(x, y) in &[(verts[0], verts[1]), (verts[1], verts[2]), (verts[2], verts[0])] {
+                    enqueue(&mut queue, &mut queued_edges, x, y, &mesh.vertices);
+                }
+            }
+        }
+
+        mesh.triangle_indices = mesh.triangle_indices.iter().enumerate()
+            .filter(|(tri_idx, _)| !removed_triangles[*tri_idx])
+            .map(|(_, verts)| *verts)
+            .collect();
+        mesh.triangles = mesh.triangle_indices.iter()
+            .map(|v| vec![mesh.vertices[v[0]], mesh.vertices[v[1]], mesh.vertices[v[2]]])
+            .collect();
+
+        let quality_after = self.calculate_enhanced_quality(mesh);
+        (quality_before, quality_after)
+    }
+
     fn calculate_enhanced_quality(&self, mesh: &Mesh) -> f64 {
         if mesh.triangles.is_empty() {
             return 0.0;
@@ -644,15 +1249,18 @@ impl GeneralAnnealingOptimizer {
 
                 // Volume uniformity check
                 if self.check_volume {
-                    let volume = triangle.volume(&mesh.vertices);
-                    let volume_quality = self.calculate_volume_quality(volume, mesh);
+                    let area = triangle.area(&mesh.vertices);
+                    let volume_quality = self.calculate_volume_quality(area, mesh);
                     quality_score += volume_quality * self.volume_weight;
                     weight_sum += self.volume_weight;
                 }
 
-                // Aspect ratio check
+                // Aspect ratio check, via whichever shape metric `self.quality_metric` selects
                 if self.check_aspect_ratio {
-                    let aspect_ratio = triangle.aspect_ratio(&mesh.vertices);
+                    let aspect_ratio = match self.quality_metric {
+                        QualityMetric::AngleJacobian => triangle.min_angle(&mesh.vertices) / 60.0,
+                        QualityMetric::RadiusRatio => triangle.radius_ratio(&mesh.vertices),
+                    };
                     let aspect_quality = self.calculate_aspect_ratio_quality(aspect_ratio);
                     quality_score += aspect_quality * self.aspect_ratio_weight;
                     weight_sum += self.aspect_ratio_weight;
@@ -660,12 +1268,20 @@ impl GeneralAnnealingOptimizer {
 
                 // Size uniformity check
                 if self.check_size_uniformity {
-                    let volume = triangle.volume(&mesh.vertices);
-                    let size_quality = self.calculate_size_uniformity_quality(volume);
+                    let area = triangle.area(&mesh.vertices);
+                    let size_quality = self.calculate_size_uniformity_quality(area);
                     quality_score += size_quality * self.size_uniformity_weight;
                     weight_sum += self.size_uniformity_weight;
                 }
 
+                // Radius-ratio (shape regularity) check: 2*inradius/circumradius, 1.0 for
+                // equilateral, 0.0 for slivers and for the degenerate zero-area case.
+                if self.check_radius_ratio {
+                    let radius_ratio_quality = triangle.radius_ratio(&mesh.vertices);
+                    quality_score += radius_ratio_quality * self.radius_ratio_weight;
+                    weight_sum += self.radius_ratio_weight;
+                }
+
                 if weight_sum > 0.0 {
                     total_quality += quality_score / weight_sum;
                     valid_triangles += 1;
@@ -687,7 +1303,7 @@ impl GeneralAnnealingOptimizer {
 
         // Calculate average volume for comparison
         let total_volume: f64 = mesh.triangle_indices.iter()
-            .map(|&vertices| Triangle::new(vertices, &mesh.vertices).volume(&mesh.vertices))
+            .map(|&vertices| Triangle::new(vertices, &mesh.vertices).area(&mesh.vertices))
             .sum();
         let avg_volume = total_volume / mesh.triangle_indices.len() as f64;
 
@@ -732,46 +1348,93 @@ impl GeneralAnnealingOptimizer {
         if self.boundary_points.is_empty() {
             return 0; // No boundary constraints
         }
-        
-        let tolerance = 1e-6;
-        let mut boundary_vertex_count = 0;
-        
-        // Count vertices that are very close to any boundary point
-        for vertex in vertices {
-            for boundary_point in &self.boundary_points {
-                let distance_sq = (vertex.x - boundary_point.x).powi(2) + (vertex.y - boundary_point.y).powi(2);
-                if distance_sq < tolerance {
-                    boundary_vertex_count += 1;
-                    break; // Found match, no need to check other boundary points
-                }
-            }
-        }
-        
+
+        // Scale-aware snapping tolerance, derived from the boundary's own extent (see
+        // `boundary_tolerance`) rather than a fixed constant.
+        let tolerance = self.boundary_tolerance;
+        let index = match &self.boundary_index {
+            Some(index) => index,
+            None => return 0,
+        };
+        let fixed_point_count = self.boundary_points.len() + self.holes.iter().map(Vec::len).sum::<usize>();
+
+        // A whole-set bounding-box prefilter lets vertices far outside the fixed-point set skip
+        // the tree walk entirely; `nearest_within` still does a proper per-vertex query for the
+        // rest since the bbox alone can't tell which vertex near the boundary actually matches.
+        let bounds = index.bounds();
+        let boundary_vertex_count = vertices.iter()
+            .filter(|vertex| {
+                let in_bounds = bounds.map_or(false, |(min_x, min_y, max_x, max_y)| {
+                    vertex.x >= min_x - tolerance && vertex.x <= max_x + tolerance &&
+                    vertex.y >= min_y - tolerance && vertex.y <= max_y + tolerance
+                });
+                in_bounds && index.nearest_within(vertex, tolerance)
+            })
+            .count();
+
         // If we didn't find enough matches, be conservative and protect more vertices
-        if boundary_vertex_count < self.boundary_points.len() {
-            let conservative_estimate = self.boundary_points.len().min(vertices.len() / 3);
-            log::info!("BOUNDARY DETECTION - Found {} exact matches, using conservative estimate of {}", 
+        if boundary_vertex_count < fixed_point_count {
+            let conservative_estimate = fixed_point_count.min(vertices.len() / 3);
+            log::info!("BOUNDARY DETECTION - Found {} exact matches, using conservative estimate of {}",
                       boundary_vertex_count, conservative_estimate);
             return conservative_estimate;
         }
-        
-        log::info!("BOUNDARY DETECTION - Identified {} boundary vertices out of {} total", 
+
+        log::info!("BOUNDARY DETECTION - Identified {} boundary vertices out of {} total",
                   boundary_vertex_count, vertices.len());
         boundary_vertex_count
     }
 
-    fn update_triangles_after_vertex_move(&self, mesh: &mut Mesh, moved_vertex_idx: usize) {
-        // Update triangles that contain the moved vertex
-        for (_i, triangle_vertices) in mesh.triangle_indices.iter().enumerate() {
-            if triangle_vertices.contains(&moved_vertex_idx) {
-                let triangle_points = vec![
-                    mesh.vertices[triangle_vertices[0]].clone(),
-                    mesh.vertices[triangle_vertices[1]].clone(),
-                    mesh.vertices[triangle_vertices[2]].clone(),
-                ];
-                mesh.triangles[_i] = triangle_points;
+    /// Repairs just the moved vertex's star via `DelaunayTriangulator::move_vertex` instead of
+    /// retriangulating the whole mesh, then refreshes `mesh.triangle_indices`/`mesh.triangles`
+    /// from the updated adjacency.
+    fn update_triangles_after_vertex_move(&mut self, mesh: &mut Mesh, moved_vertex_idx: usize) -> Result<(), String> {
+        let triangulator = match self.triangulator.as_mut() {
+            Some(t) => t,
+            None => return Ok(()),
+        };
+
+        let new_point = mesh.vertices[moved_vertex_idx];
+        triangulator.move_vertex(moved_vertex_idx, new_point)?;
+
+        mesh.triangle_indices = triangulator.live_triangles().iter().map(|t| t.vertices).collect();
+        mesh.triangles = mesh.triangle_indices.iter()
+            .map(|v| vec![
+                mesh.vertices[v[0]].clone(),
+                mesh.vertices[v[1]].clone(),
+                mesh.vertices[v[2]].clone(),
+            ])
+            .collect();
+
+        Ok(())
+    }
+
+    /// Area-weighted centroid of the triangles incident to `mesh.vertices[vertex_idx]` (the
+    /// polygon-gravity-center construction: each sub-triangle's centroid weighted by its own
+    /// area). `None` if the vertex has no incident triangles.
+    fn area_weighted_centroid_for_mesh(mesh: &Mesh, vertex_idx: usize) -> Option<Point> {
+        let mut weighted_x = 0.0;
+        let mut weighted_y = 0.0;
+        let mut total_area = 0.0;
+
+        for tri in &mesh.triangle_indices {
+            if !tri.contains(&vertex_idx) {
+                continue;
             }
+
+            let (pa, pb, pc) = (mesh.vertices[tri[0]], mesh.vertices[tri[1]], mesh.vertices[tri[2]]);
+            let area = ((pb.x - pa.x) * (pc.y - pa.y) - (pc.x - pa.x) * (pb.y - pa.y)).abs() / 2.0;
+
+            weighted_x += area * (pa.x + pb.x + pc.x) / 3.0;
+            weighted_y += area * (pa.y + pb.y + pc.y) / 3.0;
+            total_area += area;
         }
+
+        if total_area < 1e-12 {
+            return None;
+        }
+
+        Some(Point::new(weighted_x / total_area, weighted_y / total_area))
     }
 
     fn is_point_inside_boundary(&self, point: &Point) -> bool {
@@ -779,41 +1442,33 @@ impl GeneralAnnealingOptimizer {
         if self.boundary_points.is_empty() {
             return true;
         }
-        
-        // Use ray casting algorithm to check if point is inside polygon
-        let mut inside = false;
-        let boundary_count = self.boundary_points.len();
-        let mut j = boundary_count - 1;
 
-        for i in 0..boundary_count {
-            let pi = &self.boundary_points[i];
-            let pj = &self.boundary_points[j];
-            
-            if ((pi.y > point.y) != (pj.y > point.y)) &&
-               (point.x < (pj.x - pi.x) * (point.y - pi.y) / (pj.y - pi.y) + pi.x) {
-                inside = !inside;
+        // Cheap whole-set bbox reject before the O(n) polygon winding scans below - the combined
+        // boundary+holes bbox equals the outer boundary's bbox as long as holes sit inside it.
+        if let Some(bounds) = self.boundary_index.as_ref().and_then(BoundaryIndex::bounds) {
+            let (min_x, min_y, max_x, max_y) = bounds;
+            if point.x < min_x || point.x > max_x || point.y < min_y || point.y > max_y {
+                return false;
             }
-            j = i;
         }
-        
-        inside
+
+        let mut loops: Vec<&[Point]> = vec![&self.boundary_points];
+        loops.extend(self.holes.iter().map(Vec::as_slice));
+        crate::geometry::winding_number_inside(&loops, point)
     }
 
     fn is_boundary_vertex(&self, vertex: &Point) -> bool {
         if self.boundary_points.is_empty() {
             return false;
         }
-        
-        let tolerance = 1e-6;
-        
-        // Check if this vertex is very close to any boundary point
-        for boundary_point in &self.boundary_points {
-            let distance_sq = (vertex.x - boundary_point.x).powi(2) + (vertex.y - boundary_point.y).powi(2);
-            if distance_sq < tolerance {
-                return true;
-            }
+
+        // Scale-aware snapping tolerance, derived from the boundary's own extent (see
+        // `boundary_tolerance`) rather than a fixed constant.
+        let tolerance = self.boundary_tolerance;
+
+        match &self.boundary_index {
+            Some(index) => index.nearest_within(vertex, tolerance),
+            None => false,
         }
-        
-        false
     }
 }
\ No newline at end of file
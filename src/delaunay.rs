@@ -1,18 +1,72 @@
 use std::f64;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use crate::geometry::Point;
-use crate::elements::{Triangle, Edge};
+use crate::elements::Triangle;
 use crate::mesh::Mesh;
+use crate::spatial_index::{Aabb, CircumcircleIndex};
+
+/// Sign of the orientation of `c` relative to the directed line `a -> b`, routed through the
+/// crate's adaptive-precision predicate so grid-aligned or collinear inputs get an exact sign
+/// instead of a plain-`f64` determinant that can flip under rounding.
+fn robust_orient2d(a: &Point, b: &Point, c: &Point) -> f64 {
+    crate::predicates::orient2d(a.x, a.y, b.x, b.y, c.x, c.y)
+}
+
+/// Adaptive-precision replacement for `Triangle::contains_point_in_circumcircle`: exact even
+/// when `point` is nearly cocircular with the triangle. `in_circle` assumes `a, b, c` are given
+/// counter-clockwise, so the triangle's own orientation (also computed exactly) picks the sign
+/// convention to use.
+fn robust_in_circumcircle(triangle: &Triangle, points: &[Point], point: &Point) -> bool {
+    let a = &points[triangle.vertices[0]];
+    let b = &points[triangle.vertices[1]];
+    let c = &points[triangle.vertices[2]];
+
+    let det = crate::predicates::in_circle(a.x, a.y, b.x, b.y, c.x, c.y, point.x, point.y);
+
+    if robust_orient2d(a, b, c) > 0.0 {
+        det > 0.0
+    } else {
+        det < 0.0
+    }
+}
+
+/// What lies across a triangle's directed edge: either the triangle on the other side, or
+/// `Border` when the edge is unmatched (the hull boundary, or a hole once one is carved out).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EdgeNeighbor {
+    Triangle(usize),
+    Border,
+}
 
 pub struct DelaunayTriangulator {
     pub points: Vec<Point>,
-    pub triangles: Vec<Triangle>,
+    /// Tombstoned so triangle ids stay stable across insertions: a removed triangle becomes
+    /// `None` in place rather than shifting every later id via `Vec::remove`.
+    triangles: Vec<Option<Triangle>>,
+    /// Directed edge `(a, b)` -> id of the triangle that owns it (i.e. whose CCW winding
+    /// includes `a -> b`). The triangle across that edge, if any, owns the reverse edge
+    /// `(b, a)` — see `neighbor_across`.
+    adjacency: HashMap<(usize, usize), usize>,
+    /// Edges that must survive as-is: both directions are stored so a constrained check from
+    /// either endpoint order succeeds. Populated by `insert_segment` and consulted by
+    /// `flood_remove` so region classification never crosses a boundary or hole edge.
+    constrained_edges: HashSet<(usize, usize)>,
+    last_triangle: usize,
     pub super_triangle_indices: [usize; 3],
+    /// Accelerates `bowyer_watson_add_point`'s fallback seed search (taken whenever `locate`'s
+    /// walk fails to find a containing triangle) by bbox-prefiltering live triangles'
+    /// circumcircles instead of running the exact in-circle test on every one of them. Only
+    /// built once `points.len()` clears `CIRCUMCIRCLE_INDEX_THRESHOLD`, and only when
+    /// `use_circumcircle_index` hasn't been turned off - so small inputs keep paying nothing for
+    /// it, matching `spatial_index::BoundaryIndex`'s own small-input skip.
+    circumcircle_index: Option<CircumcircleIndex>,
+    use_circumcircle_index: bool,
 }
 
 impl DelaunayTriangulator {
     pub fn new(mut points: Vec<Point>) -> Self {
-        let bounds = Self::calculate_bounds(&points);
-        let super_triangle = Self::create_super_triangle(bounds);
+        let super_triangle = Self::create_super_triangle(&points);
         
         // Store original point count before adding super triangle
         let original_count = points.len();
@@ -23,21 +77,195 @@ impl DelaunayTriangulator {
         let mut triangulator = Self {
             points,
             triangles: Vec::new(),
+            adjacency: HashMap::new(),
+            constrained_edges: HashSet::new(),
+            last_triangle: 0,
             super_triangle_indices,
+            circumcircle_index: None,
+            use_circumcircle_index: true,
         };
-        
+
         // Create super triangle with proper orientation
-        let super_triangle = Triangle::new(super_triangle_indices, &triangulator.points);
-        
-        if super_triangle.jacobian(&triangulator.points) < 0.0 {
+        let a = &triangulator.points[super_triangle_indices[0]];
+        let b = &triangulator.points[super_triangle_indices[1]];
+        let c = &triangulator.points[super_triangle_indices[2]];
+
+        if robust_orient2d(a, b, c) < 0.0 {
             triangulator.super_triangle_indices = [super_triangle_indices[2], super_triangle_indices[1], super_triangle_indices[0]];
         }
-        
-        triangulator.triangles.push(Triangle::new(triangulator.super_triangle_indices, &triangulator.points));
-        
+
+        let super_triangle_indices = triangulator.super_triangle_indices;
+        triangulator.last_triangle = triangulator.push_triangle(super_triangle_indices);
+
         triangulator
     }
 
+    /// Below this many points (the super triangle's three vertices included), the exact linear scan
+    /// `bowyer_watson_add_point`'s fallback already falls back to is cheap enough that building
+    /// and maintaining `circumcircle_index` would cost more than it saves.
+    const CIRCUMCIRCLE_INDEX_THRESHOLD: usize = 64;
+
+    fn push_triangle(&mut self, vertices: [usize; 3]) -> usize {
+        let id = self.triangles.len();
+        let triangle = Triangle::new(vertices, &self.points);
+        if self.use_circumcircle_index && self.points.len() >= Self::CIRCUMCIRCLE_INDEX_THRESHOLD {
+            let bbox = Aabb::of_circle(triangle.circumcenter, triangle.circumradius_squared);
+            match &mut self.circumcircle_index {
+                Some(index) => index.insert(id, bbox),
+                None => self.circumcircle_index = Some(CircumcircleIndex::build(vec![(id, bbox)])),
+            }
+        }
+        self.triangles.push(Some(triangle));
+        for i in 0..3 {
+            let a = vertices[i];
+            let b = vertices[(i + 1) % 3];
+            self.adjacency.insert((a, b), id);
+        }
+        id
+    }
+
+    fn remove_triangle(&mut self, id: usize) {
+        if let Some(tri) = self.triangles[id].take() {
+            if let Some(index) = &mut self.circumcircle_index {
+                index.remove(id);
+            }
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if self.adjacency.get(&(a, b)) == Some(&id) {
+                    self.adjacency.remove(&(a, b));
+                }
+            }
+        }
+    }
+
+    /// Lets callers opt out of `circumcircle_index` (e.g. for a reproducible byte-for-byte
+    /// comparison against the plain linear-scan fallback).
+    pub fn set_circumcircle_index_enabled(&mut self, enabled: bool) {
+        self.use_circumcircle_index = enabled;
+        if !enabled {
+            self.circumcircle_index = None;
+        }
+    }
+
+    fn neighbor_across(&self, a: usize, b: usize) -> EdgeNeighbor {
+        match self.adjacency.get(&(b, a)) {
+            Some(&id) => EdgeNeighbor::Triangle(id),
+            None => EdgeNeighbor::Border,
+        }
+    }
+
+    /// The vertex of `tri` that follows the directed edge `(a, b)` the triangle owns, i.e. the
+    /// apex of the fan `a -> b -> apex -> a`.
+    fn third_vertex(tri: &Triangle, a: usize, b: usize) -> usize {
+        let v = tri.vertices;
+        for i in 0..3 {
+            if v[i] == a && v[(i + 1) % 3] == b {
+                return v[(i + 2) % 3];
+            }
+        }
+        unreachable!("edge ({}, {}) is not part of this triangle", a, b)
+    }
+
+    /// Pushes `(a, b, c)` as a new triangle, flipping the winding first if needed so it is
+    /// always CCW — the same "fix it up, don't assume it" convention `paving.rs` uses for its
+    /// grid quads and boundary fan.
+    fn push_triangle_ccw(&mut self, a: usize, b: usize, c: usize) -> usize {
+        let vertices = if robust_orient2d(&self.points[a], &self.points[b], &self.points[c]) < 0.0 {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        };
+        self.push_triangle(vertices)
+    }
+
+    fn mark_constrained(&mut self, a: usize, b: usize) {
+        self.constrained_edges.insert((a, b));
+        self.constrained_edges.insert((b, a));
+    }
+
+    fn is_constrained(&self, a: usize, b: usize) -> bool {
+        self.constrained_edges.contains(&(a, b))
+    }
+
+    /// Finds a triangle incident to `u` whose far edge `(x, y)` the open segment `u -> v`
+    /// crosses: `v` must lie inside the wedge swept CCW from ray `u->x` to ray `u->y`.
+    fn find_wedge_triangle(&self, u: usize, v: usize) -> Option<(usize, usize, usize)> {
+        let pu = self.points[u];
+        let pv = self.points[v];
+
+        for (&(a, b), &id) in self.adjacency.iter() {
+            if a != u {
+                continue;
+            }
+            let tri = match self.triangles[id].as_ref() {
+                Some(t) => t,
+                None => continue,
+            };
+            let y = Self::third_vertex(tri, a, b);
+
+            if robust_orient2d(&pu, &self.points[b], &pv) > 0.0
+                && robust_orient2d(&pu, &self.points[y], &pv) < 0.0
+            {
+                return Some((id, b, y));
+            }
+        }
+
+        None
+    }
+
+    /// Walks from the last-inserted triangle toward `p`, crossing whichever edge `p` lies
+    /// outside of, until it lands in the triangle containing `p`. Returns `None` if the walk
+    /// runs off the hull (`p` outside the triangulated region) instead of looping forever.
+    fn locate(&self, p: &Point) -> Option<usize> {
+        let mut current = if self.triangles.get(self.last_triangle).map_or(false, |t| t.is_some()) {
+            self.last_triangle
+        } else {
+            self.triangles.iter().position(|t| t.is_some())?
+        };
+
+        for _ in 0..self.triangles.len() + 1 {
+            let tri = self.triangles[current].as_ref()?;
+            let [a, b, c] = tri.vertices;
+            let edges = [(a, b), (b, c), (c, a)];
+            let mut moved = false;
+
+            for (ea, eb) in edges {
+                if robust_orient2d(&self.points[ea], &self.points[eb], p) < 0.0 {
+                    match self.neighbor_across(ea, eb) {
+                        EdgeNeighbor::Triangle(next) => {
+                            current = next;
+                            moved = true;
+                            break;
+                        }
+                        EdgeNeighbor::Border => return None,
+                    }
+                }
+            }
+
+            if !moved {
+                return Some(current);
+            }
+        }
+
+        None
+    }
+
+    /// Bbox-prefiltered fallback for when `locate`'s walk runs off the hull: checks only the
+    /// triangles `circumcircle_index` says could plausibly contain `point`, confirming each
+    /// candidate with the exact `robust_in_circumcircle` test. `None` if the index isn't built
+    /// (small input, or disabled via `set_circumcircle_index_enabled`) - callers then fall back
+    /// to the plain linear scan over every live triangle.
+    fn find_seed_via_circumcircle_index(&self, point: &Point) -> Option<usize> {
+        let index = self.circumcircle_index.as_ref()?;
+        index.candidates_containing(point).into_iter()
+            .find(|&id| self.triangles[id].as_ref().map_or(false, |tri| robust_in_circumcircle(tri, &self.points, point)))
+    }
+
+    pub fn live_triangles(&self) -> Vec<Triangle> {
+        self.triangles.iter().filter_map(|t| t.clone()).collect()
+    }
+
     pub fn calculate_bounds(points: &[Point]) -> (f64, f64, f64, f64) {
         let mut min_x = f64::INFINITY;
         let mut max_x = f64::NEG_INFINITY;
@@ -54,210 +282,797 @@ impl DelaunayTriangulator {
         (min_x, max_x, min_y, max_y)
     }
 
-    fn create_super_triangle(bounds: (f64, f64, f64, f64)) -> Vec<Point> {
-        let (min_x, max_x, min_y, max_y) = bounds;
-        let dx = max_x - min_x;
-        let dy = max_y - min_y;
-        let delta_max = dx.max(dy);
-        let mid_x = (min_x + max_x) / 2.0;
-        let mid_y = (min_y + max_y) / 2.0;
+    /// Equilateral triangle enclosing `points`, sized from their convex hull instead of a fixed
+    /// bounding-box margin: centered on the hull's centroid with an inradius of (farthest hull
+    /// vertex from the centroid) * `SUPER_TRIANGLE_SAFETY_FACTOR`, which stays tight for
+    /// off-origin, elongated, or L-shaped point sets where an axis-aligned bbox would need a much
+    /// larger (and more numerically fragile) margin to guarantee every point lands strictly
+    /// inside.
+    fn create_super_triangle(points: &[Point]) -> Vec<Point> {
+        const SUPER_TRIANGLE_SAFETY_FACTOR: f64 = 3.0;
+
+        let hull_indices = crate::geometry::convex_hull(points);
+        let hull: Vec<Point> = hull_indices.iter().map(|&i| points[i]).collect();
+
+        let centroid = Point::new(
+            hull.iter().map(|p| p.x).sum::<f64>() / hull.len() as f64,
+            hull.iter().map(|p| p.y).sum::<f64>() / hull.len() as f64,
+        );
+
+        let radius = hull.iter()
+            .map(|p| centroid.distance_to(p))
+            .fold(0.0_f64, f64::max)
+            .max(1.0)
+            * SUPER_TRIANGLE_SAFETY_FACTOR;
 
         vec![
-            Point::new(mid_x - 20.0 * delta_max, mid_y - delta_max),
-            Point::new(mid_x, mid_y + 20.0 * delta_max),
-            Point::new(mid_x + 20.0 * delta_max, mid_y - delta_max),
+            Point::new(centroid.x - radius * 3.0_f64.sqrt(), centroid.y - radius),
+            Point::new(centroid.x + radius * 3.0_f64.sqrt(), centroid.y - radius),
+            Point::new(centroid.x, centroid.y + 2.0 * radius),
         ]
     }
 
     pub fn triangulate(&mut self) -> Result<Mesh, String> {
         let original_point_count = self.points.len() - 3;
-        
+
         // Apply Bowyer-Watson algorithm for each point
         for i in 0..original_point_count {
             self.bowyer_watson_add_point(i)?;
         }
 
         self.remove_super_triangle();
-        
+
         let vertices: Vec<Point> = self.points[..original_point_count].to_vec();
-        let triangles = self.triangles.clone();
-        
+        let triangles = self.live_triangles();
+
         Ok(Mesh::new(vertices, triangles))
     }
 
-    // Proper Bowyer-Watson algorithm implementation
-    pub fn bowyer_watson_add_point(&mut self, point_index: usize) -> Result<(), String> {
-        let point = &self.points[point_index];
-        let mut bad_triangles = Vec::new();
+    /// Below this point count, one-at-a-time Bowyer-Watson insertion (`triangulate`) is already
+    /// fast enough that the bookkeeping `triangulate_bulk_load` needs to maintain the advancing
+    /// front isn't worth it; callers should fall back to `triangulate` under this threshold.
+    pub const BULK_LOAD_THRESHOLD: usize = 500;
+
+    /// Alternative to `triangulate` for large point sets: the circle-sweep bulk-loading
+    /// algorithm (Biniaz & Dastghaibyfard) instead of plain Bowyer-Watson insertion. Points are
+    /// sorted by distance from a centroid seed and added in that order against an advancing
+    /// front, which keeps each insertion local to the handful of front edges near the new point
+    /// instead of walking/flooding the whole triangulation for every one of the thousands of
+    /// points a dense initial mesh can have. The super triangle from `new` isn't needed here -
+    /// circle-sweep builds its own hull from scratch - so it's discarded up front.
+    pub fn triangulate_bulk_load(&mut self) -> Result<Mesh, String> {
+        let original_point_count = self.points.len() - 3;
+        if original_point_count < 3 {
+            return self.triangulate();
+        }
+
+        self.remove_triangle(self.last_triangle);
+
+        let sum = self.points[..original_point_count].iter()
+            .fold(Point::new(0.0, 0.0), |acc, p| Point::new(acc.x + p.x, acc.y + p.y));
+        let center = Point::new(sum.x / original_point_count as f64, sum.y / original_point_count as f64);
+
+        let mut order: Vec<usize> = (0..original_point_count).collect();
+        order.sort_by(|&a, &b| {
+            let da = self.points[a].distance_to(&center);
+            let db = self.points[b].distance_to(&center);
+            da.partial_cmp(&db).unwrap()
+        });
+
+        let (p0, p1, p2) = (order[0], order[1], order[2]);
+        self.last_triangle = self.push_triangle_ccw(p0, p1, p2);
+
+        let mut hull: Vec<usize> = vec![p0, p1, p2];
+        hull.sort_by(|&a, &b| {
+            self.polar_angle(a, center).partial_cmp(&self.polar_angle(b, center)).unwrap()
+        });
+
+        for &point_idx in &order[3..] {
+            self.insert_from_hull(point_idx, &mut hull, center)?;
+        }
+
+        let vertices: Vec<Point> = self.points[..original_point_count].to_vec();
+        let triangles = self.live_triangles();
+
+        Ok(Mesh::new(vertices, triangles))
+    }
+
+    /// Polar angle of point `idx` around `center`; `hull` is kept sorted by this so the front
+    /// edge nearest a new point's angle is a binary search instead of a linear scan.
+    fn polar_angle(&self, idx: usize, center: Point) -> f64 {
+        (self.points[idx].y - center.y).atan2(self.points[idx].x - center.x)
+    }
+
+    /// Extends the advancing front with `point_idx`: finds the front edge at `point_idx`'s polar
+    /// angle, then walks right and left from it absorbing every further front edge the new point
+    /// can see (one new triangle per edge absorbed), splices the consumed run of front vertices
+    /// down to just `point_idx`, and legalizes every newly shared edge with Lawson flips so the
+    /// front's interior stays Delaunay.
+    fn insert_from_hull(&mut self, point_idx: usize, hull: &mut Vec<usize>, center: Point) -> Result<(), String> {
+        let n = hull.len();
+        if n < 2 {
+            return Err("advancing front is degenerate".to_string());
+        }
+
+        let angle = self.polar_angle(point_idx, center);
+        let start = hull.partition_point(|&v| self.polar_angle(v, center) < angle) % n;
+
+        let mut new_edges = Vec::new();
+
+        let mut right = start;
+        loop {
+            let a = hull[right];
+            let b = hull[(right + 1) % n];
+            if robust_orient2d(&self.points[a], &self.points[b], &self.points[point_idx]) >= 0.0 {
+                break;
+            }
+            self.push_triangle_ccw(a, b, point_idx);
+            new_edges.push((a, b));
+            right = (right + 1) % n;
+            if right == start {
+                break;
+            }
+        }
+
+        let mut left = start;
+        loop {
+            let prev = (left + n - 1) % n;
+            let a = hull[prev];
+            let b = hull[left];
+            if robust_orient2d(&self.points[a], &self.points[b], &self.points[point_idx]) >= 0.0 {
+                break;
+            }
+            self.push_triangle_ccw(a, b, point_idx);
+            new_edges.push((a, b));
+            left = prev;
+            if left == right {
+                break;
+            }
+        }
+
+        let mut next_hull = Vec::with_capacity(n + 1);
+        next_hull.push(point_idx);
+        let mut i = (right + 1) % n;
+        while i != (left + 1) % n {
+            next_hull.push(hull[i]);
+            i = (i + 1) % n;
+        }
+        *hull = next_hull;
+        hull.sort_by(|&a, &b| {
+            self.polar_angle(a, center).partial_cmp(&self.polar_angle(b, center)).unwrap()
+        });
+
+        self.legalize(new_edges);
 
-        // Find triangles whose circumcircle contains the point
-        for (i, triangle) in self.triangles.iter().enumerate() {
-            if triangle.contains_point_in_circumcircle(point) {
-                bad_triangles.push(i);
+        Ok(())
+    }
+
+    /// Lawson edge-flip legalization: for each candidate edge `(a, b)` shared by two triangles,
+    /// flips it to the other diagonal when that restores the empty-circumcircle property, then
+    /// re-queues the two edges of each new triangle that weren't the flipped diagonal itself -
+    /// the same propagation a single Bowyer-Watson insertion gets "for free" from its cavity
+    /// flood-fill, needed here because advancing-front insertion only touches the front.
+    fn legalize(&mut self, mut queue: Vec<(usize, usize)>) {
+        while let Some((a, b)) = queue.pop() {
+            let id_ab = match self.adjacency.get(&(a, b)) {
+                Some(&id) => id,
+                None => continue,
+            };
+            let id_ba = match self.neighbor_across(a, b) {
+                EdgeNeighbor::Triangle(id) => id,
+                EdgeNeighbor::Border => continue,
+            };
+
+            let tri_ab = match &self.triangles[id_ab] {
+                Some(t) => t.clone(),
+                None => continue,
+            };
+            let tri_ba = match &self.triangles[id_ba] {
+                Some(t) => t.clone(),
+                None => continue,
+            };
+
+            let c = Self::third_vertex(&tri_ab, a, b);
+            let d = Self::third_vertex(&tri_ba, b, a);
+
+            if !robust_in_circumcircle(&tri_ab, &self.points, &self.points[d]) {
+                continue;
             }
+
+            self.remove_triangle(id_ab);
+            self.remove_triangle(id_ba);
+            self.push_triangle_ccw(c, d, a);
+            self.push_triangle_ccw(d, c, b);
+
+            queue.push((a, d));
+            queue.push((d, b));
+            queue.push((b, c));
+            queue.push((c, a));
         }
+    }
 
-        // Find boundary of polygonal hole
-        let mut polygon_edges = Vec::new();
-        for &bad_triangle_index in &bad_triangles {
-            let triangle = &self.triangles[bad_triangle_index];
+    /// Bowyer-Watson point insertion accelerated by the adjacency map: the containing triangle
+    /// is found by walking from the last-inserted triangle (falling back to `circumcircle_index`
+    /// - and, failing that, a full scan - only if the walk runs off the hull), and the
+    /// bad-triangle cavity is found by flooding outward from it across edges whose neighbor's
+    /// circumcircle also contains the point — so neither step re-examines the whole mesh.
+    pub fn bowyer_watson_add_point(&mut self, point_index: usize) -> Result<(), String> {
+        let point = self.points[point_index];
+
+        let seed = self.locate(&point)
+            .or_else(|| self.find_seed_via_circumcircle_index(&point))
+            .or_else(|| {
+                self.triangles.iter().position(|t| {
+                    t.as_ref().map_or(false, |tri| robust_in_circumcircle(tri, &self.points, &point))
+                })
+            });
+
+        let seed = match seed {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+
+        let mut cavity = HashSet::new();
+        let mut stack = vec![seed];
+        cavity.insert(seed);
+
+        while let Some(id) = stack.pop() {
+            let tri = self.triangles[id].as_ref().unwrap().clone();
             for i in 0..3 {
-                let edge = Edge::new(triangle.vertices[i], triangle.vertices[(i + 1) % 3]);
-                
-                // Check if this edge is shared with another bad triangle
-                let mut is_shared = false;
-                for &other_bad_triangle_index in &bad_triangles {
-                    if other_bad_triangle_index == bad_triangle_index {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if let EdgeNeighbor::Triangle(neighbor) = self.neighbor_across(a, b) {
+                    if cavity.contains(&neighbor) {
                         continue;
                     }
-                    
-                    let other_triangle = &self.triangles[other_bad_triangle_index];
-                    if self.triangle_contains_edge(other_triangle, &edge) {
-                        is_shared = true;
-                        break;
+                    if let Some(ntri) = &self.triangles[neighbor] {
+                        if robust_in_circumcircle(ntri, &self.points, &point) {
+                            cavity.insert(neighbor);
+                            stack.push(neighbor);
+                        }
                     }
                 }
-                
-                // If edge is not shared, it's part of the polygon boundary
-                if !is_shared {
-                    polygon_edges.push(edge);
+            }
+        }
+
+        // Boundary edges of the cavity: those whose neighbor either isn't part of the cavity
+        // or is a border, found directly from the adjacency map instead of an O(cavity^2)
+        // shared-edge search.
+        let mut boundary = Vec::new();
+        for &id in &cavity {
+            let tri = self.triangles[id].as_ref().unwrap();
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                let outside = match self.neighbor_across(a, b) {
+                    EdgeNeighbor::Triangle(neighbor) => !cavity.contains(&neighbor),
+                    EdgeNeighbor::Border => true,
+                };
+                if outside {
+                    boundary.push((a, b));
                 }
             }
         }
 
-        // Remove bad triangles (in reverse order to maintain indices)
-        bad_triangles.sort_by(|a, b| b.cmp(a));
-        for &index in &bad_triangles {
-            self.triangles.remove(index);
+        for &id in &cavity {
+            self.remove_triangle(id);
         }
 
-        // Add new triangles formed by connecting point to polygon
-        for edge in polygon_edges {
-            let vertices = [edge.vertices[0], edge.vertices[1], point_index];
-            let new_triangle = Triangle::new(vertices, &self.points);
-            
-            // Ensure proper orientation
-            if new_triangle.jacobian(&self.points) > 0.0 {
-                self.triangles.push(new_triangle);
+        for (a, b) in boundary {
+            self.last_triangle = self.push_triangle([a, b, point_index]);
+        }
+
+        Ok(())
+    }
+
+    // Keep the old add_point method for backward compatibility
+    pub fn add_point(&mut self, point_index: usize) -> Result<(), String> {
+        self.bowyer_watson_add_point(point_index)
+    }
+
+    /// Relocates an already-triangulated interior vertex without rebuilding the mesh: its
+    /// incident triangles (the vertex's "star") are torn out, the polygon ring left behind is
+    /// re-triangulated in place of the old position, then the vertex is reinserted at
+    /// `new_point` through the same Bowyer-Watson cavity used for fresh points. Cavity insertion
+    /// only ever grows into triangles whose circumcircle the new point violates, which is the
+    /// same criterion Lawson edge-flip legalization converges to, so no separate flip pass is
+    /// needed. Proportional to the vertex's degree rather than the whole mesh, so annealing can
+    /// call this once per accepted/rejected perturbation instead of retriangulating from scratch.
+    /// Only valid for an interior vertex (one whose star closes into a full ring); callers must
+    /// not use this for hull/boundary vertices.
+    pub fn move_vertex(&mut self, vertex: usize, new_point: Point) -> Result<(), String> {
+        let incident: Vec<usize> = self.triangles.iter().enumerate()
+            .filter_map(|(id, slot)| match slot {
+                Some(tri) if tri.vertices.contains(&vertex) => Some(id),
+                _ => None,
+            })
+            .collect();
+
+        if incident.is_empty() {
+            self.points[vertex] = new_point;
+            return Ok(());
+        }
+
+        let ring = self.star_ring(vertex, &incident)?;
+
+        for &id in &incident {
+            self.remove_triangle(id);
+        }
+
+        self.triangulate_pseudo_polygon(&ring);
+        self.points[vertex] = new_point;
+        self.bowyer_watson_add_point(vertex)
+    }
+
+    /// Walks `vertex`'s incident triangles into the ordered cycle of "far" vertices (the ones not
+    /// touching `vertex`) that bounds its star, so the star can be re-triangulated and `vertex`
+    /// reinserted. Consecutive incident triangles share one far-edge endpoint, so the cycle is
+    /// found by chaining each far edge's start to its end until the walk returns to where it
+    /// began; if it doesn't close after visiting every incident triangle, `vertex` sits on the
+    /// hull and has no closed star.
+    fn star_ring(&self, vertex: usize, incident: &[usize]) -> Result<Vec<usize>, String> {
+        let mut next_of = HashMap::new();
+        for &id in incident {
+            let tri = self.triangles[id].as_ref().unwrap();
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if a != vertex && b != vertex {
+                    next_of.insert(a, b);
+                }
+            }
+        }
+
+        let start = *next_of.keys().next()
+            .ok_or_else(|| format!("vertex {} has no star to rebuild", vertex))?;
+        let mut ring = vec![start];
+        let mut current = start;
+        loop {
+            let next = *next_of.get(&current)
+                .ok_or_else(|| format!("vertex {}'s star is not a closed fan (likely a hull vertex)", vertex))?;
+            if next == start {
+                break;
+            }
+            ring.push(next);
+            current = next;
+            if ring.len() > next_of.len() {
+                return Err(format!("vertex {}'s star ring failed to close", vertex));
+            }
+        }
+
+        if ring.len() < 3 {
+            return Err(format!("vertex {}'s star ring is degenerate", vertex));
+        }
+
+        Ok(ring)
+    }
+
+    /// Enforces the segment `(u, v)` as an edge of the triangulation. If it's already an edge,
+    /// this just flags it; otherwise it walks the triangles the open segment crosses, removes
+    /// them to open a cavity split by the segment into an upper and lower pseudo-polygon, and
+    /// re-triangulates each half so the segment becomes shared between them. Either way `(u, v)`
+    /// is marked constrained so later flood-fill classification and refinement leave it alone.
+    pub fn insert_segment(&mut self, u: usize, v: usize) -> Result<(), String> {
+        if u == v {
+            return Ok(());
+        }
+        if self.adjacency.contains_key(&(u, v)) || self.adjacency.contains_key(&(v, u)) {
+            self.mark_constrained(u, v);
+            return Ok(());
+        }
+
+        let pu = self.points[u];
+        let pv = self.points[v];
+
+        let (start_id, mut a, mut b) = self.find_wedge_triangle(u, v).ok_or_else(|| {
+            format!("no triangle incident to point {} faces point {}", u, v)
+        })?;
+
+        let mut crossed = vec![start_id];
+        let a_is_upper = robust_orient2d(&pu, &pv, &self.points[a]) > 0.0;
+        let (mut upper, mut lower) = if a_is_upper {
+            (vec![u, a], vec![u, b])
+        } else {
+            (vec![u, b], vec![u, a])
+        };
+
+        loop {
+            let next_id = match self.neighbor_across(a, b) {
+                EdgeNeighbor::Triangle(id) => id,
+                EdgeNeighbor::Border => {
+                    return Err(format!("segment ({}, {}) runs off the triangulated region", u, v));
+                }
+            };
+            crossed.push(next_id);
+
+            let tri = self.triangles[next_id]
+                .as_ref()
+                .ok_or_else(|| "segment walk stepped onto a removed triangle".to_string())?;
+            let c = Self::third_vertex(tri, b, a);
+
+            if c == v {
+                upper.push(v);
+                lower.push(v);
+                break;
+            }
+
+            let c_is_upper = robust_orient2d(&pu, &pv, &self.points[c]) > 0.0;
+            if c_is_upper {
+                upper.push(c);
             } else {
-                let corrected_vertices = [edge.vertices[1], edge.vertices[0], point_index];
-                self.triangles.push(Triangle::new(corrected_vertices, &self.points));
+                lower.push(c);
             }
+
+            if c_is_upper == a_is_upper {
+                a = c;
+            } else {
+                b = c;
+            }
+        }
+
+        for &id in &crossed {
+            self.remove_triangle(id);
         }
 
+        self.triangulate_pseudo_polygon(&upper);
+        self.triangulate_pseudo_polygon(&lower);
+        self.mark_constrained(u, v);
+
         Ok(())
     }
-    
-    fn triangle_contains_edge(&self, triangle: &Triangle, edge: &Edge) -> bool {
-        for i in 0..3 {
-            let triangle_edge = Edge::new(triangle.vertices[i], triangle.vertices[(i + 1) % 3]);
-            if triangle_edge == *edge {
-                return true;
+
+    /// Recursively fills the pseudo-polygon `chain` (a walk from one endpoint of a constrained
+    /// segment to the other, collected while `insert_segment` removed the triangles it crosses)
+    /// so no new edge crosses the segment: the split point is the first one whose triangle with
+    /// the chain's two endpoints has none of the chain's other points inside its circumcircle,
+    /// the same empty-circumcircle property an ordinary Delaunay triangle has.
+    fn triangulate_pseudo_polygon(&mut self, chain: &[usize]) {
+        if chain.len() < 3 {
+            return;
+        }
+        if chain.len() == 3 {
+            self.push_triangle_ccw(chain[0], chain[1], chain[2]);
+            return;
+        }
+
+        let u = chain[0];
+        let v = *chain.last().unwrap();
+        let mut split = 1;
+
+        for i in 1..chain.len() - 1 {
+            let candidate = Triangle::new([u, chain[i], v], &self.points);
+            let clean = (1..chain.len() - 1).filter(|&j| j != i).all(|j| {
+                !robust_in_circumcircle(&candidate, &self.points, &self.points[chain[j]])
+            });
+            if clean {
+                split = i;
+                break;
             }
         }
-        false
-    }
-    
-    // Keep the old add_point method for backward compatibility
-    pub fn add_point(&mut self, point_index: usize) -> Result<(), String> {
-        self.bowyer_watson_add_point(point_index)
+
+        let c = chain[split];
+        self.push_triangle_ccw(u, c, v);
+        self.triangulate_pseudo_polygon(&chain[..=split]);
+        self.triangulate_pseudo_polygon(&chain[split..]);
     }
 
     pub fn remove_super_triangle(&mut self) {
-        // Remove triangles that share vertices with super-triangle
-        self.triangles.retain(|triangle| {
-            !triangle.vertices.iter().any(|&v| {
-                self.super_triangle_indices.contains(&v)
-            })
-        });
+        let ids: Vec<usize> = self.triangles.iter().enumerate()
+            .filter_map(|(id, slot)| slot.as_ref().map(|tri| (id, tri.vertices)))
+            .filter(|(_, vertices)| vertices.iter().any(|v| self.super_triangle_indices.contains(v)))
+            .map(|(id, _)| id)
+            .collect();
+
+        for id in ids {
+            self.remove_triangle(id);
+        }
     }
 
     pub fn filter_outside_triangles(&mut self, boundary_count: usize) {
-        let points = &self.points;
-        self.triangles.retain(|triangle| {
+        let snapshot: Vec<(usize, Triangle)> = self.triangles.iter().enumerate()
+            .filter_map(|(id, slot)| slot.clone().map(|tri| (id, tri)))
+            .collect();
+
+        for (id, triangle) in snapshot {
             let centroid = Point::new(
-                (points[triangle.vertices[0]].x + points[triangle.vertices[1]].x + points[triangle.vertices[2]].x) / 3.0,
-                (points[triangle.vertices[0]].y + points[triangle.vertices[1]].y + points[triangle.vertices[2]].y) / 3.0,
+                (self.points[triangle.vertices[0]].x + self.points[triangle.vertices[1]].x + self.points[triangle.vertices[2]].x) / 3.0,
+                (self.points[triangle.vertices[0]].y + self.points[triangle.vertices[1]].y + self.points[triangle.vertices[2]].y) / 3.0,
             );
-            
-            let mut inside = false;
-            let mut j = boundary_count - 1;
 
-            for i in 0..boundary_count {
-                let pi = &points[i];
-                let pj = &points[j];
-                
-                if ((pi.y > centroid.y) != (pj.y > centroid.y)) &&
-                   (centroid.x < (pj.x - pi.x) * (centroid.y - pi.y) / (pj.y - pi.y) + pi.x) {
-                    inside = !inside;
-                }
-                j = i;
+            if !self.is_point_inside_polygon(&centroid, boundary_count) {
+                self.remove_triangle(id);
             }
-            
-            inside
-        });
+        }
     }
 
-    pub fn refine_interior(&mut self, max_area: f64, _min_angle: f64, boundary_count: usize) -> Result<(), String> {
-        let mut iteration = 0;
-        let max_iterations = 50;
-        let max_points = 10000;
+    fn triangle_centroid(&self, tri: &Triangle) -> Point {
+        Point::new(
+            (self.points[tri.vertices[0]].x + self.points[tri.vertices[1]].x + self.points[tri.vertices[2]].x) / 3.0,
+            (self.points[tri.vertices[0]].y + self.points[tri.vertices[1]].y + self.points[tri.vertices[2]].y) / 3.0,
+        )
+    }
 
-        while iteration < max_iterations && self.points.len() < max_points {
-            let mut needs_refinement = false;
-            let mut bad_triangles = Vec::new();
+    /// Removes every triangle reachable from `seed` by crossing only non-constrained edges, so
+    /// the flood stops dead at any boundary or hole segment `insert_segment` enforced.
+    fn flood_remove(&mut self, seed: usize) {
+        let mut stack = vec![seed];
+        let mut visited = HashSet::new();
+        visited.insert(seed);
 
-            for (i, triangle) in self.triangles.iter().enumerate() {
-                let area = triangle.area(&self.points);
-                if area > max_area {
-                    let centroid = Point::new(
-                        (self.points[triangle.vertices[0]].x + self.points[triangle.vertices[1]].x + self.points[triangle.vertices[2]].x) / 3.0,
-                        (self.points[triangle.vertices[0]].y + self.points[triangle.vertices[1]].y + self.points[triangle.vertices[2]].y) / 3.0,
-                    );
-                    
-                    if self.is_point_inside_polygon(&centroid, boundary_count) {
-                        needs_refinement = true;
-                        bad_triangles.push(i);
+        while let Some(id) = stack.pop() {
+            let tri = match self.triangles[id].clone() {
+                Some(t) => t,
+                None => continue,
+            };
+
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if self.is_constrained(a, b) {
+                    continue;
+                }
+                if let EdgeNeighbor::Triangle(next) = self.neighbor_across(a, b) {
+                    if visited.insert(next) {
+                        stack.push(next);
                     }
                 }
             }
 
-            if !needs_refinement || bad_triangles.is_empty() {
-                break;
-            }
+            self.remove_triangle(id);
+        }
+    }
 
-            let triangles_to_refine = bad_triangles.into_iter().take(5).collect::<Vec<_>>();
-            let mut new_points = Vec::new();
+    /// A triangle touching `loop_range` whose centroid falls outside `loop_points` — a seed for
+    /// `flood_remove` to clear everything reachable from outside that loop's boundary.
+    fn find_exterior_seed(&self, loop_range: &Range<usize>, loop_points: &[Point]) -> Option<usize> {
+        self.find_loop_seed(loop_range, loop_points, false)
+    }
 
-            for &triangle_index in &triangles_to_refine {
-                if triangle_index < self.triangles.len() {
-                    let triangle = &self.triangles[triangle_index];
-                    let centroid = Point::new(
-                        (self.points[triangle.vertices[0]].x + self.points[triangle.vertices[1]].x + self.points[triangle.vertices[2]].x) / 3.0,
-                        (self.points[triangle.vertices[0]].y + self.points[triangle.vertices[1]].y + self.points[triangle.vertices[2]].y) / 3.0,
-                    );
-                    
-                    if self.is_point_inside_polygon(&centroid, boundary_count) {
-                        new_points.push(centroid);
+    /// A triangle touching `loop_range` whose centroid falls inside `loop_points` — a seed for
+    /// `flood_remove` to clear a hole's interior.
+    fn find_interior_seed(&self, loop_range: &Range<usize>, loop_points: &[Point]) -> Option<usize> {
+        self.find_loop_seed(loop_range, loop_points, true)
+    }
+
+    fn find_loop_seed(&self, loop_range: &Range<usize>, loop_points: &[Point], want_inside: bool) -> Option<usize> {
+        let n = loop_range.len();
+        for i in 0..n {
+            let u = loop_range.start + i;
+            let v = loop_range.start + (i + 1) % n;
+
+            for &(a, b) in &[(u, v), (v, u)] {
+                if let Some(&id) = self.adjacency.get(&(a, b)) {
+                    if let Some(tri) = &self.triangles[id] {
+                        let centroid = self.triangle_centroid(tri);
+                        if Self::is_point_inside_boundary_static(&centroid, loop_points) == want_inside {
+                            return Some(id);
+                        }
                     }
                 }
             }
+        }
+        None
+    }
 
-            if new_points.is_empty() {
-                break;
+    /// Constrained Delaunay triangulation of one or more outer boundary loops with optional
+    /// holes: triangulates every loop vertex, enforces each loop edge as a constrained segment
+    /// via `insert_segment`, then flood-fills region labels from the outer boundary across
+    /// non-constrained edges, discarding triangles reached from outside a boundary loop or from
+    /// inside a hole loop.
+    pub fn triangulate_constrained(boundary_loops: &[Vec<Point>], hole_loops: &[Vec<Point>]) -> Result<Mesh, String> {
+        if boundary_loops.is_empty() {
+            return Err("need at least one boundary loop".to_string());
+        }
+        if boundary_loops.iter().any(|l| l.len() < 3) || hole_loops.iter().any(|l| l.len() < 3) {
+            return Err("every loop needs at least 3 points".to_string());
+        }
+
+        let mut points = Vec::new();
+        let mut boundary_ranges = Vec::new();
+        for loop_points in boundary_loops {
+            let start = points.len();
+            points.extend(loop_points.iter().cloned());
+            boundary_ranges.push(start..points.len());
+        }
+        let mut hole_ranges = Vec::new();
+        for loop_points in hole_loops {
+            let start = points.len();
+            points.extend(loop_points.iter().cloned());
+            hole_ranges.push(start..points.len());
+        }
+
+        let original_point_count = points.len();
+        let mut triangulator = DelaunayTriangulator::new(points);
+
+        for i in 0..original_point_count {
+            triangulator.bowyer_watson_add_point(i)?;
+        }
+
+        for range in boundary_ranges.iter().chain(hole_ranges.iter()) {
+            let n = range.len();
+            for i in 0..n {
+                let u = range.start + i;
+                let v = range.start + (i + 1) % n;
+                triangulator.insert_segment(u, v)?;
             }
+        }
 
-            for new_point in new_points {
-                if self.points.len() >= max_points {
-                    break;
-                }
-                let point_index = self.points.len();
-                self.points.push(new_point);
-                self.add_point(point_index)?;
+        triangulator.remove_super_triangle();
+
+        for (loop_index, range) in boundary_ranges.iter().enumerate() {
+            if let Some(seed) = triangulator.find_exterior_seed(range, &boundary_loops[loop_index]) {
+                triangulator.flood_remove(seed);
             }
+        }
+
+        for (loop_index, range) in hole_ranges.iter().enumerate() {
+            if let Some(seed) = triangulator.find_interior_seed(range, &hole_loops[loop_index]) {
+                triangulator.flood_remove(seed);
+            }
+        }
+
+        let vertices: Vec<Point> = triangulator.points[..original_point_count].to_vec();
+        let triangles = triangulator.live_triangles();
+
+        Ok(Mesh::new(vertices, triangles))
+    }
+
+    /// Constrained Delaunay triangulation of a single boundary polygon (no holes), immediately
+    /// followed by `refine_interior`: unlike `triangulate_constrained`, which drops the
+    /// `DelaunayTriangulator` as soon as its `Mesh` is built, this keeps it alive long enough to
+    /// insert Ruppert's Steiner points before handing back the final, refined `Mesh`.
+    pub fn triangulate_constrained_refined(
+        boundary: &[Point],
+        max_area: f64,
+        min_angle: f64,
+    ) -> Result<Mesh, String> {
+        if boundary.len() < 3 {
+            return Err("boundary needs at least 3 points".to_string());
+        }
+
+        let boundary_count = boundary.len();
+        let mut triangulator = DelaunayTriangulator::new(boundary.to_vec());
+
+        for i in 0..boundary_count {
+            triangulator.bowyer_watson_add_point(i)?;
+        }
+        for i in 0..boundary_count {
+            triangulator.insert_segment(i, (i + 1) % boundary_count)?;
+        }
+
+        triangulator.remove_super_triangle();
+
+        let loop_range = 0..boundary_count;
+        if let Some(seed) = triangulator.find_exterior_seed(&loop_range, boundary) {
+            triangulator.flood_remove(seed);
+        }
+
+        triangulator.refine_interior(max_area, min_angle, boundary_count)?;
+
+        let vertices = triangulator.points.clone();
+        let triangles = triangulator.live_triangles();
+
+        Ok(Mesh::new(vertices, triangles))
+    }
+
+    /// Ruppert's algorithm: treats `points[0..boundary_count]` as a closed polygon of
+    /// constrained segments and repeatedly (1) splits any segment encroached by a mesh vertex —
+    /// one lying strictly inside the circle having that segment as diameter — at its midpoint,
+    /// or, if none are encroached, (2) finds a triangle whose smallest angle is below
+    /// `min_angle` (degrees) or whose area exceeds `max_area` and inserts its circumcenter,
+    /// unless that circumcenter would itself encroach a segment, in which case the segment is
+    /// split instead. Converges to a graded, sliver-free mesh for `min_angle` up to ~20.7°.
+    pub fn refine_interior(&mut self, max_area: f64, min_angle: f64, boundary_count: usize) -> Result<(), String> {
+        for i in 0..boundary_count {
+            self.insert_segment(i, (i + 1) % boundary_count)?;
+        }
 
+        let max_iterations = 2000;
+        let max_points = 20000;
+        let mut iteration = 0;
+
+        while iteration < max_iterations && self.points.len() < max_points {
             iteration += 1;
+
+            if let Some((u, v)) = self.find_encroached_segment() {
+                self.split_segment(u, v)?;
+                continue;
+            }
+
+            let bad_id = match self.find_bad_triangle(max_area, min_angle, boundary_count) {
+                Some(id) => id,
+                None => break,
+            };
+            let circumcenter = match &self.triangles[bad_id] {
+                Some(tri) => tri.circumcenter,
+                None => continue,
+            };
+
+            if let Some((u, v)) = self.encroached_segment_for_point(&circumcenter) {
+                self.split_segment(u, v)?;
+            } else {
+                let point_index = self.points.len();
+                self.points.push(circumcenter);
+                self.bowyer_watson_add_point(point_index)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_bad_triangle(&self, max_area: f64, min_angle: f64, boundary_count: usize) -> Option<usize> {
+        for (id, slot) in self.triangles.iter().enumerate() {
+            let tri = match slot {
+                Some(t) => t,
+                None => continue,
+            };
+            let centroid = self.triangle_centroid(tri);
+            if !self.is_point_inside_polygon(&centroid, boundary_count) {
+                continue;
+            }
+            if tri.area(&self.points) > max_area || tri.min_angle(&self.points) < min_angle {
+                return Some(id);
+            }
+        }
+        None
+    }
+
+    /// Reduces every constrained segment to a canonical `(min, max)` pair so each is tested
+    /// once even though `constrained_edges` stores both directions.
+    fn unique_constrained_segments(&self) -> Vec<(usize, usize)> {
+        let mut seen = HashSet::new();
+        for &(a, b) in self.constrained_edges.iter() {
+            seen.insert(if a < b { (a, b) } else { (b, a) });
+        }
+        seen.into_iter().collect()
+    }
+
+    fn segment_encroached_by(&self, u: usize, v: usize, point: &Point) -> bool {
+        let mid = Point::new(
+            (self.points[u].x + self.points[v].x) / 2.0,
+            (self.points[u].y + self.points[v].y) / 2.0,
+        );
+        let radius = self.points[u].distance_to(&self.points[v]) / 2.0;
+        mid.distance_to(point) < radius - 1e-10
+    }
+
+    fn find_encroached_segment(&self) -> Option<(usize, usize)> {
+        for (u, v) in self.unique_constrained_segments() {
+            let encroached = self.triangles.iter().flatten().any(|tri| {
+                tri.vertices
+                    .iter()
+                    .any(|&p| p != u && p != v && self.segment_encroached_by(u, v, &self.points[p]))
+            });
+            if encroached {
+                return Some((u, v));
+            }
         }
+        None
+    }
+
+    fn encroached_segment_for_point(&self, point: &Point) -> Option<(usize, usize)> {
+        self.unique_constrained_segments()
+            .into_iter()
+            .find(|&(u, v)| self.segment_encroached_by(u, v, point))
+    }
+
+    /// Replaces the constrained segment `(u, v)` with its midpoint and the two half-segments
+    /// `(u, mid)` and `(mid, v)`, inserting the midpoint through the normal Bowyer-Watson path.
+    fn split_segment(&mut self, u: usize, v: usize) -> Result<(), String> {
+        let midpoint = Point::new(
+            (self.points[u].x + self.points[v].x) / 2.0,
+            (self.points[u].y + self.points[v].y) / 2.0,
+        );
+
+        self.constrained_edges.remove(&(u, v));
+        self.constrained_edges.remove(&(v, u));
+
+        let point_index = self.points.len();
+        self.points.push(midpoint);
+        self.bowyer_watson_add_point(point_index)?;
+
+        self.insert_segment(u, point_index)?;
+        self.insert_segment(point_index, v)?;
 
         Ok(())
     }
@@ -279,7 +1094,157 @@ impl DelaunayTriangulator {
         
         inside
     }
-    
+
+    /// The Voronoi diagram dual to this triangulation, one polygon per site in `self.points`
+    /// (super-triangle vertices excluded): for an interior site that's the circumcenters of its
+    /// incident triangles in order, and for a hull site it's bracketed by two rays — one per
+    /// hull edge touching the site — shot along that edge's outward normal and clipped to
+    /// `bbox`. Cells on the hull aren't closed back up through the box's corners, so a site
+    /// whose two rays exit through different box edges yields an open polyline, not a polygon.
+    pub fn voronoi(&self, bbox: (f64, f64, f64, f64)) -> Vec<Vec<Point>> {
+        (0..self.points.len())
+            .filter(|i| !self.super_triangle_indices.contains(i))
+            .map(|site| self.voronoi_cell(site, bbox))
+            .collect()
+    }
+
+    fn voronoi_cell(&self, site: usize, bbox: (f64, f64, f64, f64)) -> Vec<Point> {
+        let (fan, is_hull) = self.site_fan(site);
+        if fan.is_empty() {
+            return Vec::new();
+        }
+
+        let circumcenters: Vec<Point> = fan
+            .iter()
+            .filter_map(|&id| self.triangles[id].as_ref().map(|t| t.circumcenter))
+            .collect();
+
+        if !is_hull {
+            return circumcenters;
+        }
+
+        let first_tri = self.triangles[fan[0]].as_ref().unwrap();
+        let (_, b0) = Self::triangle_rotation(first_tri, site);
+        let ray0_dir = Self::outward_normal(&self.points[b0], &self.points[site]);
+        let ray0 = Self::clip_ray_to_bbox(first_tri.circumcenter, ray0_dir, bbox);
+
+        let last_tri = self.triangles[*fan.last().unwrap()].as_ref().unwrap();
+        let (a_last, _) = Self::triangle_rotation(last_tri, site);
+        let ray1_dir = Self::outward_normal(&self.points[site], &self.points[a_last]);
+        let ray1 = Self::clip_ray_to_bbox(last_tri.circumcenter, ray1_dir, bbox);
+
+        let mut cell = vec![ray0];
+        cell.extend(circumcenters);
+        cell.push(ray1);
+        cell
+    }
+
+    /// Every triangle incident to `site`, ordered by walking the adjacency graph around it, plus
+    /// whether the walk ran off the hull (`site` is a boundary vertex) rather than closing back
+    /// on its starting triangle.
+    fn site_fan(&self, site: usize) -> (Vec<usize>, bool) {
+        let start = match self
+            .triangles
+            .iter()
+            .position(|slot| slot.as_ref().map_or(false, |t| t.vertices.contains(&site)))
+        {
+            Some(id) => id,
+            None => return (Vec::new(), false),
+        };
+
+        let mut fan = vec![start];
+        let mut is_hull = false;
+        let mut current = start;
+
+        loop {
+            let tri = self.triangles[current].as_ref().unwrap();
+            let (a, _) = Self::triangle_rotation(tri, site);
+            match self.neighbor_across(site, a) {
+                EdgeNeighbor::Triangle(next) if next != start => {
+                    fan.push(next);
+                    current = next;
+                }
+                EdgeNeighbor::Triangle(_) => break,
+                EdgeNeighbor::Border => {
+                    is_hull = true;
+                    break;
+                }
+            }
+        }
+
+        if is_hull {
+            current = start;
+            loop {
+                let tri = self.triangles[current].as_ref().unwrap();
+                let (_, b) = Self::triangle_rotation(tri, site);
+                match self.neighbor_across(b, site) {
+                    EdgeNeighbor::Triangle(next) => {
+                        fan.insert(0, next);
+                        current = next;
+                    }
+                    EdgeNeighbor::Border => break,
+                }
+            }
+        }
+
+        (fan, is_hull)
+    }
+
+    /// `(a, b)` such that `tri` owns the directed edges `(site, a)`, `(a, b)`, `(b, site)` —
+    /// i.e. `tri`'s vertex order rotated so `site` comes first.
+    fn triangle_rotation(tri: &Triangle, site: usize) -> (usize, usize) {
+        let v = tri.vertices;
+        let k = v.iter().position(|&x| x == site).unwrap();
+        (v[(k + 1) % 3], v[(k + 2) % 3])
+    }
+
+    /// Unit vector perpendicular to directed edge `p -> q`, pointing away from the mesh
+    /// interior (which this file's CCW convention always keeps to the edge's left).
+    fn outward_normal(p: &Point, q: &Point) -> (f64, f64) {
+        let dx = q.x - p.x;
+        let dy = q.y - p.y;
+        let len = (dx * dx + dy * dy).sqrt();
+        if len < 1e-12 {
+            (0.0, 0.0)
+        } else {
+            (dy / len, -dx / len)
+        }
+    }
+
+    /// The point where the ray from `origin` along `dir` first leaves `bbox`, assuming `origin`
+    /// starts inside it.
+    fn clip_ray_to_bbox(origin: Point, dir: (f64, f64), bbox: (f64, f64, f64, f64)) -> Point {
+        let (min_x, max_x, min_y, max_y) = bbox;
+        let mut t_exit = f64::INFINITY;
+
+        if dir.0.abs() > 1e-12 {
+            let tx = if dir.0 > 0.0 {
+                (max_x - origin.x) / dir.0
+            } else {
+                (min_x - origin.x) / dir.0
+            };
+            if tx >= 0.0 {
+                t_exit = t_exit.min(tx);
+            }
+        }
+        if dir.1.abs() > 1e-12 {
+            let ty = if dir.1 > 0.0 {
+                (max_y - origin.y) / dir.1
+            } else {
+                (min_y - origin.y) / dir.1
+            };
+            if ty >= 0.0 {
+                t_exit = t_exit.min(ty);
+            }
+        }
+
+        if !t_exit.is_finite() {
+            t_exit = 0.0;
+        }
+
+        Point::new(origin.x + dir.0 * t_exit, origin.y + dir.1 * t_exit)
+    }
+
     // Method 1: Hexagonal Grid Approach (from pseudocode)
     pub fn generate_hexagonal_grid(boundary: &[Point], target_edge_length: f64) -> Vec<Point> {
         let mut points = Vec::new();
@@ -440,6 +1405,306 @@ impl DelaunayTriangulator {
             triangulation = triangulator.triangulate()?;
         }
         
-        Ok(triangulator.triangles)
+        Ok(triangulator.live_triangles())
+    }
+
+    /// Centroidal Voronoi relaxation (Lloyd's algorithm): a cheaper alternative to full Ruppert
+    /// refinement for fill meshing. Each iteration triangulates `points`, computes every interior
+    /// point's Voronoi cell, clips that cell against `boundary`, and moves the point to the
+    /// clipped cell's area-weighted centroid. Points that lie on `boundary` are left fixed so the
+    /// outline is preserved; after a few iterations the interior points settle into a
+    /// near-uniform, isotropic distribution.
+    pub fn lloyd_relax(points: &[Point], boundary: &[Point], iterations: usize) -> Vec<Point> {
+        if points.len() < 4 || boundary.len() < 3 {
+            return points.to_vec();
+        }
+
+        let bbox = Self::calculate_bounds(boundary);
+        let fixed: Vec<bool> = points.iter().map(|p| Self::is_on_boundary(p, boundary)).collect();
+        let mut current = points.to_vec();
+
+        for _ in 0..iterations {
+            let mut triangulator = DelaunayTriangulator::new(current.clone());
+            for i in 0..current.len() {
+                let _ = triangulator.bowyer_watson_add_point(i);
+            }
+
+            let cells = triangulator.voronoi(bbox);
+            let mut next = current.clone();
+
+            for (i, is_fixed) in fixed.iter().enumerate() {
+                if *is_fixed {
+                    continue;
+                }
+                let cell = match cells.get(i) {
+                    Some(c) if c.len() >= 3 => c,
+                    _ => continue,
+                };
+                let clipped = Self::clip_polygon_to_boundary(cell, boundary);
+                if clipped.len() >= 3 {
+                    next[i] = Self::polygon_centroid(&clipped);
+                }
+            }
+
+            current = next;
+        }
+
+        current
+    }
+
+    /// True if `point` lies on (within tolerance of) an edge of `boundary`, used by
+    /// `lloyd_relax` to keep boundary-sampled points from drifting.
+    fn is_on_boundary(point: &Point, boundary: &[Point]) -> bool {
+        let epsilon = 1e-6;
+        let n = boundary.len();
+        for i in 0..n {
+            let a = &boundary[i];
+            let b = &boundary[(i + 1) % n];
+            if Self::point_segment_distance(point, a, b) < epsilon {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn point_segment_distance(point: &Point, a: &Point, b: &Point) -> f64 {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+        let len_sq = dx * dx + dy * dy;
+        if len_sq < 1e-18 {
+            return point.distance_to(a);
+        }
+        let t = (((point.x - a.x) * dx + (point.y - a.y) * dy) / len_sq).clamp(0.0, 1.0);
+        let projection = Point::new(a.x + t * dx, a.y + t * dy);
+        point.distance_to(&projection)
+    }
+
+    /// Sutherland-Hodgman-style clip of a Voronoi `cell` against `boundary`: vertices inside the
+    /// boundary are kept as-is, and every cell edge that crosses the boundary outline is cut at
+    /// the crossing point. Exact for convex boundaries; for a concave boundary this can miss a
+    /// re-entrant notch that cuts the same cell edge more than once, which is an acceptable
+    /// approximation for seeding a fill mesh.
+    fn clip_polygon_to_boundary(cell: &[Point], boundary: &[Point]) -> Vec<Point> {
+        let n = cell.len();
+        let mut result = Vec::new();
+
+        for i in 0..n {
+            let curr = cell[i];
+            let next = cell[(i + 1) % n];
+            let curr_in = Self::is_point_inside_boundary_static(&curr, boundary);
+            let next_in = Self::is_point_inside_boundary_static(&next, boundary);
+
+            if curr_in {
+                result.push(curr);
+            }
+
+            if curr_in != next_in {
+                if let Some(hit) = Self::segment_boundary_crossing(&curr, &next, boundary) {
+                    result.push(hit);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// The closest point along `boundary`'s edges where segment `p -> q` crosses it.
+    fn segment_boundary_crossing(p: &Point, q: &Point, boundary: &[Point]) -> Option<Point> {
+        let n = boundary.len();
+        let mut best: Option<(f64, Point)> = None;
+
+        for i in 0..n {
+            let a = &boundary[i];
+            let b = &boundary[(i + 1) % n];
+            if let Some((t, hit)) = Self::segment_intersection(p, q, a, b) {
+                if best.as_ref().map_or(true, |&(best_t, _)| t < best_t) {
+                    best = Some((t, hit));
+                }
+            }
+        }
+
+        best.map(|(_, hit)| hit)
+    }
+
+    /// Intersection of segments `p1->p2` and `p3->p4`, returning the parameter `t` along
+    /// `p1->p2` and the hit point when the segments actually cross.
+    fn segment_intersection(p1: &Point, p2: &Point, p3: &Point, p4: &Point) -> Option<(f64, Point)> {
+        let d1x = p2.x - p1.x;
+        let d1y = p2.y - p1.y;
+        let d2x = p4.x - p3.x;
+        let d2y = p4.y - p3.y;
+
+        let denom = d1x * d2y - d1y * d2x;
+        if denom.abs() < 1e-12 {
+            return None;
+        }
+
+        let t = ((p3.x - p1.x) * d2y - (p3.y - p1.y) * d2x) / denom;
+        let u = ((p3.x - p1.x) * d1y - (p3.y - p1.y) * d1x) / denom;
+
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            Some((t, Point::new(p1.x + t * d1x, p1.y + t * d1y)))
+        } else {
+            None
+        }
+    }
+
+    /// Area-weighted centroid of a simple polygon via the shoelace formula; falls back to the
+    /// plain vertex average when the polygon is degenerate (near-zero area).
+    fn polygon_centroid(points: &[Point]) -> Point {
+        let n = points.len();
+        let mut signed_area2 = 0.0;
+        let mut cx = 0.0;
+        let mut cy = 0.0;
+
+        for i in 0..n {
+            let p0 = points[i];
+            let p1 = points[(i + 1) % n];
+            let cross = p0.x * p1.y - p1.x * p0.y;
+            signed_area2 += cross;
+            cx += (p0.x + p1.x) * cross;
+            cy += (p0.y + p1.y) * cross;
+        }
+
+        if signed_area2.abs() < 1e-12 {
+            let sum_x: f64 = points.iter().map(|p| p.x).sum();
+            let sum_y: f64 = points.iter().map(|p| p.y).sum();
+            return Point::new(sum_x / n as f64, sum_y / n as f64);
+        }
+
+        let signed_area = signed_area2 / 2.0;
+        Point::new(cx / (6.0 * signed_area), cy / (6.0 * signed_area))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Four points placed exactly on a common circle are the textbook case that trips up a
+    /// plain-`f64` incircle test: `bowyer_watson_add_point` routing through the adaptive
+    /// `predicates::in_circle` (rather than `Triangle::contains_point_in_circumcircle`'s naive
+    /// determinant) should still produce a valid two-triangle Delaunay mesh instead of
+    /// corrupting the adjacency map or panicking on a degenerate comparison.
+    #[test]
+    fn triangulate_handles_cocircular_points() {
+        let points = vec![
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(-1.0, 0.0),
+            Point::new(0.0, -1.0),
+        ];
+
+        let mut triangulator = DelaunayTriangulator::new(points);
+        let mesh = triangulator.triangulate().expect("cocircular input should still triangulate");
+
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.triangle_indices.len(), 2);
+    }
+
+    /// A square outer boundary with a smaller square hole: every boundary and hole edge must
+    /// survive `insert_segment`'s enforcement as an actual mesh edge, and `flood_remove`'s
+    /// interior-seed flood must strip every triangle whose centroid falls inside the hole.
+    #[test]
+    fn triangulate_constrained_enforces_boundary_and_hole_segments() {
+        let outer = vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ];
+        let hole = vec![
+            Point::new(4.0, 4.0),
+            Point::new(6.0, 4.0),
+            Point::new(6.0, 6.0),
+            Point::new(4.0, 6.0),
+        ];
+
+        let mesh = DelaunayTriangulator::triangulate_constrained(&[outer.clone()], &[hole.clone()])
+            .expect("constrained triangulation should succeed");
+
+        assert!(!mesh.triangle_indices.is_empty());
+
+        let find_index = |p: &Point| {
+            mesh.vertices.iter().position(|v| v.distance_to(p) < 1e-9)
+                .expect("constrained loop vertex should be present in the output mesh")
+        };
+
+        let mesh_edges: HashSet<(usize, usize)> = mesh.triangle_indices.iter()
+            .flat_map(|&[a, b, c]| {
+                let canonical = |u: usize, v: usize| if u < v { (u, v) } else { (v, u) };
+                vec![canonical(a, b), canonical(b, c), canonical(c, a)]
+            })
+            .collect();
+
+        for loop_points in [&outer, &hole] {
+            let indices: Vec<usize> = loop_points.iter().map(find_index).collect();
+            for i in 0..indices.len() {
+                let (u, v) = (indices[i], indices[(i + 1) % indices.len()]);
+                let canonical = if u < v { (u, v) } else { (v, u) };
+                assert!(mesh_edges.contains(&canonical), "constrained edge {u}-{v} missing from mesh");
+            }
+        }
+
+        for &[a, b, c] in &mesh.triangle_indices {
+            let centroid = Point::new(
+                (mesh.vertices[a].x + mesh.vertices[b].x + mesh.vertices[c].x) / 3.0,
+                (mesh.vertices[a].y + mesh.vertices[b].y + mesh.vertices[c].y) / 3.0,
+            );
+            assert!(!point_in_polygon(&centroid, &hole), "triangle centroid fell inside the hole");
+        }
+    }
+
+    /// A long, thin rectangle - every boundary corner is a comfortable 90 degrees, so nothing
+    /// about the input geometry itself caps the achievable minimum angle, unlike a sliver
+    /// triangle whose own acute corner no amount of interior Steiner-point insertion could ever
+    /// widen. Ruppert refinement honoring `min_angle` should drive every surviving triangle's
+    /// minimum angle up to (close to) the requested bound.
+    #[test]
+    fn refine_interior_honors_min_angle() {
+        let boundary = vec![
+            Point::new(0.0, 0.0),
+            Point::new(20.0, 0.0),
+            Point::new(20.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        let boundary_count = boundary.len();
+
+        let mut triangulator = DelaunayTriangulator::new(boundary);
+        for i in 0..boundary_count {
+            triangulator.bowyer_watson_add_point(i).expect("boundary points should insert cleanly");
+        }
+
+        let min_angle = 20.0;
+        triangulator.refine_interior(1.0, min_angle, boundary_count)
+            .expect("refinement should converge");
+
+        triangulator.remove_super_triangle();
+        triangulator.filter_outside_triangles(boundary_count);
+
+        let triangles = triangulator.live_triangles();
+        assert!(!triangles.is_empty());
+
+        let worst_angle = triangles.iter()
+            .map(|t| t.min_angle(&triangulator.points))
+            .fold(f64::INFINITY, f64::min);
+
+        assert!(worst_angle >= min_angle - 1.0, "worst angle {worst_angle} fell well short of {min_angle}");
+    }
+}
+
+/// Even-odd ray cast used only by this module's tests to check a centroid against a loop.
+#[cfg(test)]
+fn point_in_polygon(point: &Point, polygon: &[Point]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let (xi, yi) = (polygon[i].x, polygon[i].y);
+        let (xj, yj) = (polygon[j].x, polygon[j].y);
+        if ((yi > point.y) != (yj > point.y)) && (point.x < (xj - xi) * (point.y - yi) / (yj - yi) + xi) {
+            inside = !inside;
+        }
+        j = i;
     }
+    inside
 }
\ No newline at end of file
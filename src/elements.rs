@@ -100,6 +100,38 @@ impl Triangle {
     pub fn is_properly_oriented(&self, points: &[Point]) -> bool {
         self.jacobian(points) > 0.0
     }
+
+    /// Radius-ratio quality `q = 2r/R` (inradius over circumradius, doubled): 1 for an
+    /// equilateral triangle, tending to 0 for slivers. Unlike `min_angle`/`jacobian`, it's scale
+    /// invariant, so it stays meaningful as a target across triangles of very different sizes.
+    /// Area is computed from the vertex whose own angle is closest to 90 degrees rather than
+    /// always vertex 0, since that vertex's two adjacent edges are the most nearly perpendicular
+    /// and so the least prone to catastrophic cancellation on near-degenerate triangles.
+    pub fn radius_ratio(&self, points: &[Point]) -> f64 {
+        let p = [&points[self.vertices[0]], &points[self.vertices[1]], &points[self.vertices[2]]];
+
+        let a = p[1].distance_to(p[2]);
+        let b = p[0].distance_to(p[2]);
+        let c = p[0].distance_to(p[1]);
+
+        if a * b * c == 0.0 {
+            return 0.0;
+        }
+
+        let angles = self.angles(points);
+        let best = (0..3)
+            .min_by(|&i, &j| (angles[i] - 90.0).abs().partial_cmp(&(angles[j] - 90.0).abs()).unwrap())
+            .unwrap();
+        let (u, v, w) = (p[best], p[(best + 1) % 3], p[(best + 2) % 3]);
+        let area = 0.5 * ((v.x - u.x) * (w.y - u.y) - (w.x - u.x) * (v.y - u.y)).abs();
+
+        if area < 1e-12 {
+            return 0.0;
+        }
+
+        let s = (a + b + c) / 2.0;
+        (8.0 * area * area / (s * a * b * c)).clamp(0.0, 1.0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -168,6 +168,17 @@ fn is_point_inside_polygon(point: &Point, polygon: &[Point]) -> bool {
     inside
 }
 
+/// Writes the mesh as a flat (z=0) Wavefront OBJ document, for downstream CAD/slicer tools.
+pub fn export_to_obj(mesh: &Mesh) -> String {
+    crate::obj_io::write_obj(mesh)
+}
+
+/// Extrudes the mesh into a prism of `thickness` and writes it as binary STL, for 3D printing or
+/// FEM preprocessing from a 2D mesh.
+pub fn export_to_stl(mesh: &Mesh, thickness: f64) -> Vec<u8> {
+    crate::obj_io::export_to_stl(mesh, thickness)
+}
+
 pub fn export_to_csv(geometry: &Geometry, mesh: Option<&Mesh>) -> Result<String, String> {
     let mut csv_content = String::new();
     csv_content.push_str("Type,Index,X,Y,Additional_Info\n");
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::ops::{Add, Div, Mul, Sub};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Point {
@@ -12,7 +13,478 @@ impl Point {
     }
 
     pub fn distance_to(&self, other: &Point) -> f64 {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+        self.distance_squared_to(other).sqrt()
+    }
+
+    pub fn distance_squared_to(&self, other: &Point) -> f64 {
+        (self.x - other.x).powi(2) + (self.y - other.y).powi(2)
+    }
+
+    pub fn dot(&self, other: &Point) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Scalar (z-component of the) cross product of the two points treated as vectors.
+    pub fn cross(&self, other: &Point) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// The perpendicular vector `(-y, x)`, i.e. this vector rotated 90 degrees CCW.
+    pub fn normal(&self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Point;
+    fn mul(self, scalar: f64) -> Point {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Div<f64> for Point {
+    type Output = Point;
+    fn div(self, scalar: f64) -> Point {
+        Point::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+/// A 2D affine map `p -> (m[0]*x + m[1]*y + m[2], m[3]*x + m[4]*y + m[5])`, i.e. the top two rows
+/// of a 3x3 homogeneous transform matrix with the implicit bottom row `[0, 0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Affine2 {
+    pub m: [f64; 6],
+}
+
+impl Affine2 {
+    pub const IDENTITY: Affine2 = Affine2 { m: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0] };
+
+    pub fn translate(dx: f64, dy: f64) -> Affine2 {
+        Affine2 { m: [1.0, 0.0, dx, 0.0, 1.0, dy] }
+    }
+
+    pub fn rotate(angle_radians: f64) -> Affine2 {
+        let (s, c) = angle_radians.sin_cos();
+        Affine2 { m: [c, -s, 0.0, s, c, 0.0] }
+    }
+
+    pub fn scale(sx: f64, sy: f64) -> Affine2 {
+        Affine2 { m: [sx, 0.0, 0.0, 0.0, sy, 0.0] }
+    }
+
+    pub fn apply(&self, p: Point) -> Point {
+        let m = self.m;
+        Point::new(m[0] * p.x + m[1] * p.y + m[2], m[3] * p.x + m[4] * p.y + m[5])
+    }
+
+    /// Composes two affine maps so that `a.then(b).apply(p) == b.apply(a.apply(p))` - `self` runs
+    /// first, `other` second.
+    pub fn then(&self, other: &Affine2) -> Affine2 {
+        let a = self.m;
+        let b = other.m;
+        Affine2 {
+            m: [
+                b[0] * a[0] + b[1] * a[3],
+                b[0] * a[1] + b[1] * a[4],
+                b[0] * a[2] + b[1] * a[5] + b[2],
+                b[3] * a[0] + b[4] * a[3],
+                b[3] * a[1] + b[4] * a[4],
+                b[3] * a[2] + b[4] * a[5] + b[5],
+            ],
+        }
+    }
+}
+
+/// An undirected edge between two point indices, canonicalized to `(min, max)` on construction so
+/// the two directed half-edges of a shared side hash and compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Edge {
+    pub vertices: [usize; 2],
+}
+
+impl Edge {
+    pub fn new(a: usize, b: usize) -> Self {
+        let vertices = if a < b { [a, b] } else { [b, a] };
+        Self { vertices }
+    }
+}
+
+/// Convex hull of `points` in CCW order, as indices into `points`, via Andrew's monotone chain:
+/// sort by (x, then y), sweep left-to-right building the lower hull (popping the last hull point
+/// while the turn from it isn't left), then sweep right-to-left building the upper hull the same
+/// way, and concatenate, dropping each half's duplicated endpoint. Returns every input index if
+/// fewer than 3 points are given, since no triangle - let alone a hull - can be formed.
+pub fn convex_hull(points: &[Point]) -> Vec<usize> {
+    if points.len() < 3 {
+        return (0..points.len()).collect();
+    }
+
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| {
+        points[a].x.partial_cmp(&points[b].x).unwrap()
+            .then(points[a].y.partial_cmp(&points[b].y).unwrap())
+    });
+
+    // Cross product of (b - a) x (c - a); positive => left turn at b.
+    let cross = |a: usize, b: usize, c: usize| {
+        (points[b].x - points[a].x) * (points[c].y - points[a].y)
+            - (points[b].y - points[a].y) * (points[c].x - points[a].x)
+    };
+
+    let mut lower: Vec<usize> = Vec::new();
+    for &idx in &order {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], idx) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(idx);
+    }
+
+    let mut upper: Vec<usize> = Vec::new();
+    for &idx in order.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], idx) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(idx);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+fn signed_area(ring: &[usize], points: &[Point]) -> f64 {
+    let n = ring.len();
+    let mut area = 0.0;
+    for i in 0..n {
+        let p = points[ring[i]];
+        let q = points[ring[(i + 1) % n]];
+        area += p.x * q.y - q.x * p.y;
+    }
+    area / 2.0
+}
+
+/// True if `p` lies inside (or on the boundary of) triangle `a`-`b`-`c`, via the sign of the
+/// three edge cross products: `p` is inside iff it's never strictly on opposite sides (one
+/// negative, one positive) of the three edges.
+fn point_in_triangle(p: Point, a: Point, b: Point, c: Point) -> bool {
+    let d1 = crate::predicates::orient2d(a.x, a.y, b.x, b.y, p.x, p.y);
+    let d2 = crate::predicates::orient2d(b.x, b.y, c.x, c.y, p.x, p.y);
+    let d3 = crate::predicates::orient2d(c.x, c.y, a.x, a.y, p.x, p.y);
+
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+/// Generalized winding-number point-in-polygon test across multiple loops at once (one outer
+/// boundary plus any number of interior holes): for every edge `(a, b)` of every loop, accumulates
+/// the signed angle `atan2(cross, dot)` subtended at `point`, sums across all loops, and divides
+/// by 2*pi to get the winding number. A CCW outer loop contributes +1 for points inside it and a
+/// CW hole contributes -1, so the point is inside the meshed material exactly when the total
+/// winding number is non-zero. Unlike a single-loop even-odd ray cast, this is stable near
+/// vertices and correct for self-touching or non-convex boundaries.
+pub(crate) fn winding_number_inside(loops: &[&[Point]], point: &Point) -> bool {
+    let mut angle_sum = 0.0;
+    for loop_points in loops {
+        let n = loop_points.len();
+        if n == 0 {
+            continue;
+        }
+        for i in 0..n {
+            let a = &loop_points[i];
+            let b = &loop_points[(i + 1) % n];
+            let (ax, ay) = (a.x - point.x, a.y - point.y);
+            let (bx, by) = (b.x - point.x, b.y - point.y);
+            let cross = ax * by - ay * bx;
+            let dot = ax * bx + ay * by;
+            angle_sum += cross.atan2(dot);
+        }
+    }
+
+    let winding_number = (angle_sum / (2.0 * std::f64::consts::PI)).round() as i32;
+    winding_number != 0
+}
+
+/// True if segments `(a, b)` and `(c, d)` cross at an interior point of both (sharing an
+/// endpoint or merely touching doesn't count) - used to reject hole-bridging segments that would
+/// cut through the boundary they're meant to connect to.
+pub(crate) fn segments_properly_intersect(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let o = crate::predicates::orient2d;
+    let d1 = o(c.x, c.y, d.x, d.y, a.x, a.y);
+    let d2 = o(c.x, c.y, d.x, d.y, b.x, b.y);
+    let d3 = o(a.x, a.y, b.x, b.y, c.x, c.y);
+    let d4 = o(a.x, a.y, b.x, b.y, d.x, d.y);
+    d1 != 0.0 && d2 != 0.0 && d3 != 0.0 && d4 != 0.0 && (d1 > 0.0) != (d2 > 0.0) && (d3 > 0.0) != (d4 > 0.0)
+}
+
+/// True if the bridge segment `a_idx -> b_idx` doesn't properly cross any edge of `ring`, aside
+/// from the (at most two) edges already incident to one of its own endpoints.
+fn bridge_is_clear(a_idx: usize, b_idx: usize, ring: &[usize], points: &[Point]) -> bool {
+    let a = points[a_idx];
+    let b = points[b_idx];
+    let n = ring.len();
+    for i in 0..n {
+        let e0 = ring[i];
+        let e1 = ring[(i + 1) % n];
+        if e0 == a_idx || e1 == a_idx || e0 == b_idx || e1 == b_idx {
+            continue;
+        }
+        if segments_properly_intersect(a, b, points[e0], points[e1]) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Splices `hole` (already oriented CW) into `ring` (already oriented CCW) by connecting the
+/// hole's rightmost vertex to the nearest ring vertex with a clear line of sight, turning the
+/// outer ring plus one hole into a single simple polygon `ear_clip` can consume directly.
+fn bridge_hole(ring: &mut Vec<usize>, hole: &[usize], points: &[Point]) {
+    let hole_start = hole.iter().enumerate()
+        .max_by(|(_, &a), (_, &b)| points[a].x.partial_cmp(&points[b].x).unwrap())
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let hv_idx = hole[hole_start];
+
+    let mut candidates: Vec<usize> = (0..ring.len()).collect();
+    candidates.sort_by(|&i, &j| {
+        points[hv_idx].distance_to(&points[ring[i]])
+            .partial_cmp(&points[hv_idx].distance_to(&points[ring[j]]))
+            .unwrap()
+    });
+    let bridge_pos = candidates.into_iter()
+        .find(|&i| bridge_is_clear(hv_idx, ring[i], ring, points) && bridge_is_clear(hv_idx, ring[i], hole, points))
+        .unwrap_or(0);
+
+    let mut rotated_hole: Vec<usize> = hole[hole_start..].to_vec();
+    rotated_hole.extend_from_slice(&hole[..hole_start]);
+    rotated_hole.push(hv_idx);
+
+    let mut new_ring = Vec::with_capacity(ring.len() + rotated_hole.len() + 1);
+    new_ring.extend_from_slice(&ring[..=bridge_pos]);
+    new_ring.extend_from_slice(&rotated_hole);
+    new_ring.extend_from_slice(&ring[bridge_pos..]);
+    *ring = new_ring;
+}
+
+/// Repeatedly clips convex "ears" (three consecutive ring vertices whose triangle contains no
+/// other ring vertex) off `ring` until only a single triangle remains, producing a
+/// boundary-conforming triangulation with no Steiner points. `ring` may revisit the same point
+/// index twice (the bridge vertices `bridge_hole` introduces) - that's fine, since ears are
+/// identified by ring position, not point identity.
+fn ear_clip(mut ring: Vec<usize>, points: &[Point]) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::new();
+    let mut stalled = 0;
+
+    while ring.len() > 3 && stalled < ring.len() {
+        let n = ring.len();
+        let mut clipped = false;
+        for i in 0..n {
+            let prev = ring[(i + n - 1) % n];
+            let curr = ring[i];
+            let next = ring[(i + 1) % n];
+            let (a, b, c) = (points[prev], points[curr], points[next]);
+
+            if crate::predicates::orient2d(a.x, a.y, b.x, b.y, c.x, c.y) <= 0.0 {
+                continue;
+            }
+            let is_ear = ring.iter().all(|&idx| {
+                idx == prev || idx == curr || idx == next || !point_in_triangle(points[idx], a, b, c)
+            });
+            if is_ear {
+                triangles.push([prev, curr, next]);
+                ring.remove(i);
+                clipped = true;
+                break;
+            }
+        }
+
+        if clipped {
+            stalled = 0;
+        } else {
+            stalled += 1;
+        }
+    }
+
+    if ring.len() == 3 {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+    triangles
+}
+
+/// Ear-clipping triangulation of a polygon with holes: an exact, boundary-conforming alternative
+/// to the Delaunay/density pipeline that introduces no Steiner points, at the cost of triangle
+/// quality (slivers near reflex corners aren't avoided the way circumcircle-based refinement
+/// would). `outer` and each ring in `holes` are re-oriented (CCW / CW respectively) as needed;
+/// the caller doesn't need to pre-orient them. Returns the merged point list (outer points
+/// followed by each hole's points, in their original order) and the triangle indices into it.
+pub fn ear_clip_with_holes(outer: &[Point], holes: &[Vec<Point>]) -> (Vec<Point>, Vec<[usize; 3]>) {
+    let mut points = outer.to_vec();
+    let mut hole_rings: Vec<Vec<usize>> = Vec::with_capacity(holes.len());
+    for hole in holes {
+        let start = points.len();
+        points.extend_from_slice(hole);
+        hole_rings.push((start..points.len()).collect());
+    }
+
+    let mut ring: Vec<usize> = (0..outer.len()).collect();
+    if signed_area(&ring, &points) < 0.0 {
+        ring.reverse();
+    }
+
+    for mut hole_ring in hole_rings {
+        if signed_area(&hole_ring, &points) > 0.0 {
+            hole_ring.reverse();
+        }
+        bridge_hole(&mut ring, &hole_ring, &points);
+    }
+
+    let triangles = ear_clip(ring, &points);
+    (points, triangles)
+}
+
+/// Shortest distance from `p` to the segment `a`-`b`.
+fn distance_to_segment(p: Point, a: Point, b: Point) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.dot(&ab);
+    if len_sq < 1e-18 {
+        return p.distance_to(&a);
+    }
+    let t = ((p - a).dot(&ab) / len_sq).clamp(0.0, 1.0);
+    let closest = a + ab * t;
+    p.distance_to(&closest)
+}
+
+/// Builder-style options for `GeneralAnnealingOptimizer::from_options` - every field mirrors one
+/// of the optimizer's own tunables and is left unset (`None`) to fall back to the optimizer's own
+/// default, so callers only need to specify the handful of knobs they actually want to override.
+#[derive(Debug, Clone, Default)]
+pub struct AnnealingOptions {
+    pub temperature: Option<f64>,
+    pub cooling_rate: Option<f64>,
+    pub max_iterations: Option<u32>,
+    pub check_volume: Option<bool>,
+    pub check_aspect_ratio: Option<bool>,
+    pub target_aspect_ratio: Option<f64>,
+    pub volume_weight: Option<f64>,
+    pub aspect_ratio_weight: Option<f64>,
+    pub check_size_uniformity: Option<bool>,
+    pub size_uniformity_weight: Option<f64>,
+    pub check_radius_ratio: Option<bool>,
+    pub radius_ratio_weight: Option<f64>,
+    pub target_area: Option<f64>,
+    pub min_area: Option<f64>,
+    pub move_strategy: Option<crate::annealing::MoveStrategy>,
+    pub quality_metric: Option<crate::annealing::QualityMetric>,
+}
+
+/// A spatially-varying target element size `h(x, y)`, sampled from a coarse regular grid via
+/// bilinear interpolation - lets meshing grade element size across the domain (fine near
+/// features, coarse in the interior) instead of using one global density/`max_area`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizingField {
+    pub min: Point,
+    pub max: Point,
+    pub cols: usize,
+    pub rows: usize,
+    pub values: Vec<f64>,
+}
+
+impl SizingField {
+    /// A field with no spatial variation - every sample returns `h`, matching the old scalar
+    /// density/`max_area` behavior exactly.
+    pub fn uniform(h: f64) -> SizingField {
+        SizingField {
+            min: Point::new(0.0, 0.0),
+            max: Point::new(1.0, 1.0),
+            cols: 1,
+            rows: 1,
+            values: vec![h],
+        }
+    }
+
+    /// Distance-to-boundary grading: `h_near` right at the boundary, growing linearly out to
+    /// `h_far` once a point is `falloff` or further from every boundary edge. `resolution` sets
+    /// how many grid cells per axis the field is sampled at (higher is smoother, more memory).
+    pub fn boundary_graded(boundary: &[Point], h_near: f64, h_far: f64, falloff: f64, resolution: usize) -> SizingField {
+        let cols = resolution.max(2);
+        let rows = resolution.max(2);
+
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for p in boundary {
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        }
+
+        let n = boundary.len().max(1);
+        let mut values = Vec::with_capacity(cols * rows);
+        for row in 0..rows {
+            let y = min.y + (max.y - min.y) * row as f64 / (rows - 1) as f64;
+            for col in 0..cols {
+                let x = min.x + (max.x - min.x) * col as f64 / (cols - 1) as f64;
+                let sample = Point::new(x, y);
+
+                let dist = (0..n)
+                    .map(|i| distance_to_segment(sample, boundary[i], boundary[(i + 1) % n]))
+                    .fold(f64::INFINITY, f64::min);
+
+                let t = (dist / falloff.max(1e-9)).clamp(0.0, 1.0);
+                values.push(h_near + (h_far - h_near) * t);
+            }
+        }
+
+        SizingField { min, max, cols, rows, values }
+    }
+
+    /// Bilinearly interpolated target size at `p`, clamping queries outside the grid's bounds to
+    /// its nearest edge.
+    pub fn sample(&self, p: Point) -> f64 {
+        if self.cols <= 1 || self.rows <= 1 {
+            return self.values.first().copied().unwrap_or(1.0);
+        }
+
+        let width = (self.max.x - self.min.x).max(1e-9);
+        let height = (self.max.y - self.min.y).max(1e-9);
+        let fx = ((p.x - self.min.x) / width * (self.cols - 1) as f64).clamp(0.0, (self.cols - 1) as f64);
+        let fy = ((p.y - self.min.y) / height * (self.rows - 1) as f64).clamp(0.0, (self.rows - 1) as f64);
+
+        let x0 = fx.floor() as usize;
+        let y0 = fy.floor() as usize;
+        let x1 = (x0 + 1).min(self.cols - 1);
+        let y1 = (y0 + 1).min(self.rows - 1);
+        let tx = fx - x0 as f64;
+        let ty = fy - y0 as f64;
+
+        let v00 = self.values[y0 * self.cols + x0];
+        let v10 = self.values[y0 * self.cols + x1];
+        let v01 = self.values[y1 * self.cols + x0];
+        let v11 = self.values[y1 * self.cols + x1];
+
+        let top = v00 + (v10 - v00) * tx;
+        let bottom = v01 + (v11 - v01) * tx;
+        top + (bottom - top) * ty
     }
 }
 
@@ -49,7 +521,7 @@ impl Triangle {
         let p3 = pts[2];
 
         let d = 2.0 * (p1.x * (p2.y - p3.y) + p2.x * (p3.y - p1.y) + p3.x * (p1.y - p2.y));
-        if d.abs() < 1e-9 {
+        if crate::predicates::orient2d(p1.x, p1.y, p2.x, p2.y, p3.x, p3.y) == 0.0 {
             return None;
         }
 
@@ -90,18 +562,39 @@ impl Triangle {
 
         let s = (a + b + c) / 2.0;
         let area = (s * (s - a) * (s - b) * (s - c)).max(0.0).sqrt();
-        
+
         if area < 1e-9 {
             return f64::INFINITY;
         }
 
         let circumradius = (a * b * c) / (4.0 * area);
         let inradius = area / s;
-        
+
         if inradius < 1e-9 {
             return f64::INFINITY;
         }
 
         circumradius / (2.0 * inradius)
     }
+
+    pub fn area(&self, points: &[Point]) -> f64 {
+        let pts = self.get_points(points);
+        ((pts[1].x - pts[0].x) * (pts[2].y - pts[0].y) - (pts[2].x - pts[0].x) * (pts[1].y - pts[0].y)).abs() / 2.0
+    }
+
+    /// Radius of the circle passing through all three vertices - `f64::INFINITY` for a degenerate
+    /// (zero-area) triangle.
+    pub fn circumradius(&self, points: &[Point]) -> f64 {
+        let pts = self.get_points(points);
+        let a = pts[1].distance_to(pts[2]);
+        let b = pts[0].distance_to(pts[2]);
+        let c = pts[0].distance_to(pts[1]);
+        let area = self.area(points);
+
+        if area < 1e-9 {
+            return f64::INFINITY;
+        }
+
+        (a * b * c) / (4.0 * area)
+    }
 }
\ No newline at end of file
@@ -118,11 +118,479 @@ impl Triangle {
     }
 }
 
+fn facet_normal(a: (f64, f64, f64), b: (f64, f64, f64), c: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (ux, uy, uz) = (b.0 - a.0, b.1 - a.1, b.2 - a.2);
+    let (vx, vy, vz) = (c.0 - a.0, c.1 - a.1, c.2 - a.2);
+
+    let nx = uy * vz - uz * vy;
+    let ny = uz * vx - ux * vz;
+    let nz = ux * vy - uy * vx;
+
+    let len = (nx * nx + ny * ny + nz * nz).sqrt();
+    if len < 1e-12 {
+        (0.0, 0.0, 0.0)
+    } else {
+        (nx / len, ny / len, nz / len)
+    }
+}
+
+fn write_stl_facet(buffer: &mut Vec<u8>, a: (f64, f64, f64), b: (f64, f64, f64), c: (f64, f64, f64)) {
+    let (nx, ny, nz) = facet_normal(a, b, c);
+    buffer.extend_from_slice(&(nx as f32).to_le_bytes());
+    buffer.extend_from_slice(&(ny as f32).to_le_bytes());
+    buffer.extend_from_slice(&(nz as f32).to_le_bytes());
+
+    for v in [a, b, c] {
+        buffer.extend_from_slice(&(v.0 as f32).to_le_bytes());
+        buffer.extend_from_slice(&(v.1 as f32).to_le_bytes());
+        buffer.extend_from_slice(&(v.2 as f32).to_le_bytes());
+    }
+
+    buffer.extend_from_slice(&0u16.to_le_bytes());
+}
+
+fn canonical_edge(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+fn densify_loop(loop_points: &[Point], density: f64) -> Vec<Point> {
+    let count = loop_points.len();
+    let mut new_points = Vec::new();
+
+    for i in 0..count {
+        let current = loop_points[i];
+        let next = loop_points[(i + 1) % count];
+
+        let edge_length = current.distance_to(&next);
+        let num_segments = (edge_length / density).ceil().max(1.0) as usize;
+
+        new_points.push(current);
+
+        for j in 1..num_segments {
+            let t = j as f64 / num_segments as f64;
+            let x = current.x + t * (next.x - current.x);
+            let y = current.y + t * (next.y - current.y);
+            new_points.push(Point::new(x, y));
+        }
+    }
+
+    new_points
+}
+
+fn orient2d(a: &Point, b: &Point, c: &Point) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)
+}
+
+fn point_in_polygon(point: &Point, polygon: &[Point]) -> bool {
+    let x = point.x;
+    let y = point.y;
+    let mut inside = false;
+
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let xi = polygon[i].x;
+        let yi = polygon[i].y;
+        let xj = polygon[j].x;
+        let yj = polygon[j].y;
+
+        if ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi) {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+fn segments_properly_intersect(p1: &Point, p2: &Point, p3: &Point, p4: &Point) -> bool {
+    let d1 = orient2d(p3, p4, p1);
+    let d2 = orient2d(p3, p4, p2);
+    let d3 = orient2d(p1, p2, p3);
+    let d4 = orient2d(p1, p2, p4);
+
+    ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+}
+
+fn is_convex_quad(points: &[Point], a: usize, b: usize, c: usize, d: usize) -> bool {
+    orient2d(&points[a], &points[b], &points[c]) > 0.0
+        && orient2d(&points[b], &points[c], &points[d]) > 0.0
+        && orient2d(&points[c], &points[d], &points[a]) > 0.0
+        && orient2d(&points[d], &points[a], &points[b]) > 0.0
+}
+
+fn triangle_contains_edge(tri: &[usize; 3], a: usize, b: usize) -> bool {
+    tri.contains(&a) && tri.contains(&b)
+}
+
+fn edge_exists(triangles: &[[usize; 3]], a: usize, b: usize) -> bool {
+    triangles.iter().any(|t| triangle_contains_edge(t, a, b))
+}
+
+fn find_neighbor(triangles: &[[usize; 3]], exclude: usize, a: usize, b: usize) -> Option<usize> {
+    triangles.iter()
+        .enumerate()
+        .find(|&(i, t)| i != exclude && triangle_contains_edge(t, a, b))
+        .map(|(i, _)| i)
+}
+
+/// Flips crossed edges along the straight path from `a` to `b` until that segment appears
+/// verbatim as a mesh edge, following polyanya's outer-edge constrained recovery approach.
+fn recover_segment(points: &[Point], triangles: &mut Vec<[usize; 3]>, a: usize, b: usize) {
+    let max_iterations = triangles.len() * 2 + 16;
+
+    for _ in 0..max_iterations {
+        if edge_exists(triangles, a, b) {
+            return;
+        }
+
+        let mut flipped = false;
+
+        'search: for i in 0..triangles.len() {
+            let tri = triangles[i];
+            for k in 0..3 {
+                let e0 = tri[k];
+                let e1 = tri[(k + 1) % 3];
+                let opp = tri[(k + 2) % 3];
+
+                if e0 == a || e1 == a || e0 == b || e1 == b {
+                    continue;
+                }
+
+                if !segments_properly_intersect(&points[a], &points[b], &points[e0], &points[e1]) {
+                    continue;
+                }
+
+                if let Some(j) = find_neighbor(triangles, i, e0, e1) {
+                    let other_opp = triangles[j].iter().cloned().find(|&v| v != e0 && v != e1);
+                    if let Some(other_opp) = other_opp {
+                        if is_convex_quad(points, opp, e0, other_opp, e1) {
+                            triangles[i] = [opp, e0, other_opp];
+                            triangles[j] = [opp, other_opp, e1];
+                            flipped = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        if !flipped {
+            return;
+        }
+    }
+}
+
+fn polygon_area(points: &[Point]) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        sum += a.x * b.y - b.x * a.y;
+    }
+    (sum * 0.5).abs()
+}
+
+/// Marching-squares edge ids within a cell, in the bottom/right/top/left winding order used
+/// throughout [`march_squares`]: 0 = bottom (bl->br), 1 = right (br->tr), 2 = top (tr->tl),
+/// 3 = left (tl->bl).
+const MS_BOTTOM: usize = 0;
+const MS_RIGHT: usize = 1;
+const MS_TOP: usize = 2;
+const MS_LEFT: usize = 3;
+
+/// Looks up (or computes and caches) the point where the iso-contour crosses a grid edge,
+/// snapping to the shared grid vertex when the crossing falls within `eps` of either endpoint
+/// so that the two cells straddling that vertex agree bit-for-bit. `horizontal` edges run
+/// between `(col, row)` and `(col + 1, row)`; vertical edges between `(col, row)` and
+/// `(col, row + 1)`.
+struct MsCache {
+    points: Vec<Point>,
+    vertices: HashMap<(usize, usize), usize>,
+    horizontal: HashMap<(usize, usize), usize>,
+    vertical: HashMap<(usize, usize), usize>,
+}
+
+impl MsCache {
+    fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            vertices: HashMap::new(),
+            horizontal: HashMap::new(),
+            vertical: HashMap::new(),
+        }
+    }
+
+    fn vertex_id(&mut self, col: usize, row: usize, origin_x: f64, origin_y: f64, cell: f64) -> usize {
+        if let Some(&id) = self.vertices.get(&(col, row)) {
+            return id;
+        }
+        let id = self.points.len();
+        self.points.push(Point::new(origin_x + col as f64 * cell, origin_y + row as f64 * cell));
+        self.vertices.insert((col, row), id);
+        id
+    }
+
+    fn horizontal_id(
+        &mut self,
+        col: usize,
+        row: usize,
+        v_from: f64,
+        v_to: f64,
+        iso: f64,
+        origin_x: f64,
+        origin_y: f64,
+        cell: f64,
+    ) -> usize {
+        if let Some(&id) = self.horizontal.get(&(col, row)) {
+            return id;
+        }
+
+        const EPS: f64 = 1e-9;
+        let t = ((iso - v_from) / (v_to - v_from)).clamp(0.0, 1.0);
+        let id = if t < EPS {
+            self.vertex_id(col, row, origin_x, origin_y, cell)
+        } else if t > 1.0 - EPS {
+            self.vertex_id(col + 1, row, origin_x, origin_y, cell)
+        } else {
+            let id = self.points.len();
+            let x = origin_x + (col as f64 + t) * cell;
+            let y = origin_y + row as f64 * cell;
+            self.points.push(Point::new(x, y));
+            id
+        };
+
+        self.horizontal.insert((col, row), id);
+        id
+    }
+
+    fn vertical_id(
+        &mut self,
+        col: usize,
+        row: usize,
+        v_from: f64,
+        v_to: f64,
+        iso: f64,
+        origin_x: f64,
+        origin_y: f64,
+        cell: f64,
+    ) -> usize {
+        if let Some(&id) = self.vertical.get(&(col, row)) {
+            return id;
+        }
+
+        const EPS: f64 = 1e-9;
+        let t = ((iso - v_from) / (v_to - v_from)).clamp(0.0, 1.0);
+        let id = if t < EPS {
+            self.vertex_id(col, row, origin_x, origin_y, cell)
+        } else if t > 1.0 - EPS {
+            self.vertex_id(col, row + 1, origin_x, origin_y, cell)
+        } else {
+            let id = self.points.len();
+            let x = origin_x + col as f64 * cell;
+            let y = origin_y + (row as f64 + t) * cell;
+            self.points.push(Point::new(x, y));
+            id
+        };
+
+        self.vertical.insert((col, row), id);
+        id
+    }
+}
+
+/// Extracts the boundary of `{(x, y) : value(x, y) < iso}` from a sampled scalar field via
+/// marching squares, returning one or more closed, duplicate-point-free loops (see module-level
+/// usage in [`Mesher::generate_mesh_from_field`]). Saddle cells (diagonally opposite corners on
+/// the same side of `iso`) are resolved by comparing the cell-center average against `iso`, per
+/// the classic marching-squares ambiguity rule.
+fn march_squares(
+    values: &[f64],
+    width: usize,
+    height: usize,
+    origin_x: f64,
+    origin_y: f64,
+    cell: f64,
+    iso: f64,
+) -> Vec<Vec<Point>> {
+    if width < 2 || height < 2 || values.len() < width * height {
+        return Vec::new();
+    }
+
+    let mut cache = MsCache::new();
+    let mut next: HashMap<usize, usize> = HashMap::new();
+
+    for row in 0..height - 1 {
+        for col in 0..width - 1 {
+            let vbl = values[row * width + col];
+            let vbr = values[row * width + col + 1];
+            let vtr = values[(row + 1) * width + col + 1];
+            let vtl = values[(row + 1) * width + col];
+
+            let bl = vbl < iso;
+            let br = vbr < iso;
+            let tr = vtr < iso;
+            let tl = vtl < iso;
+
+            let case = (bl as u8) | (br as u8) << 1 | (tr as u8) << 2 | (tl as u8) << 3;
+            if case == 0 || case == 15 {
+                continue;
+            }
+
+            let mut edge_point = |cache: &mut MsCache, edge: usize| -> usize {
+                match edge {
+                    MS_BOTTOM => cache.horizontal_id(col, row, vbl, vbr, iso, origin_x, origin_y, cell),
+                    MS_TOP => cache.horizontal_id(col, row + 1, vtl, vtr, iso, origin_x, origin_y, cell),
+                    MS_LEFT => cache.vertical_id(col, row, vbl, vtl, iso, origin_x, origin_y, cell),
+                    MS_RIGHT => cache.vertical_id(col + 1, row, vbr, vtr, iso, origin_x, origin_y, cell),
+                    _ => unreachable!(),
+                }
+            };
+
+            let segments: &[(usize, usize)] = match case {
+                1 => &[(MS_BOTTOM, MS_LEFT)],
+                2 => &[(MS_RIGHT, MS_BOTTOM)],
+                3 => &[(MS_RIGHT, MS_LEFT)],
+                4 => &[(MS_TOP, MS_RIGHT)],
+                6 => &[(MS_TOP, MS_BOTTOM)],
+                7 => &[(MS_TOP, MS_LEFT)],
+                8 => &[(MS_LEFT, MS_TOP)],
+                9 => &[(MS_BOTTOM, MS_TOP)],
+                11 => &[(MS_RIGHT, MS_TOP)],
+                12 => &[(MS_LEFT, MS_RIGHT)],
+                13 => &[(MS_BOTTOM, MS_RIGHT)],
+                14 => &[(MS_LEFT, MS_BOTTOM)],
+                5 => {
+                    let center = (vbl + vbr + vtr + vtl) / 4.0;
+                    if center < iso {
+                        &[(MS_BOTTOM, MS_RIGHT), (MS_TOP, MS_LEFT)]
+                    } else {
+                        &[(MS_BOTTOM, MS_LEFT), (MS_TOP, MS_RIGHT)]
+                    }
+                }
+                10 => {
+                    let center = (vbl + vbr + vtr + vtl) / 4.0;
+                    if center < iso {
+                        &[(MS_LEFT, MS_BOTTOM), (MS_RIGHT, MS_TOP)]
+                    } else {
+                        &[(MS_RIGHT, MS_BOTTOM), (MS_LEFT, MS_TOP)]
+                    }
+                }
+                _ => &[],
+            };
+
+            for &(from_edge, to_edge) in segments {
+                let from = edge_point(&mut cache, from_edge);
+                let to = edge_point(&mut cache, to_edge);
+                if from != to {
+                    next.insert(from, to);
+                }
+            }
+        }
+    }
+
+    let mut loops = Vec::new();
+    let mut visited: HashSet<usize> = HashSet::new();
+
+    let starts: Vec<usize> = next.keys().cloned().collect();
+    for start in starts {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut loop_ids = Vec::new();
+        let mut current = start;
+        loop {
+            if !visited.insert(current) {
+                break;
+            }
+            loop_ids.push(current);
+            match next.get(&current) {
+                Some(&n) if n == start => break,
+                Some(&n) => current = n,
+                None => break,
+            }
+        }
+
+        if loop_ids.len() >= 3 {
+            loops.push(loop_ids.iter().map(|&id| cache.points[id]).collect());
+        }
+    }
+
+    loops
+}
+
+/// Flood-fills triangle adjacency starting from triangles known to be outside the domain
+/// (outside the outer loop, or inside a hole loop), never crossing a constrained edge, so
+/// every seeded triangle (hole interiors included) and everything reachable from one is
+/// discarded, and the rest is kept.
+fn classify_inside(
+    points: &[Point],
+    triangles: &[[usize; 3]],
+    outer: &[Point],
+    holes: &[Vec<Point>],
+    constraints: &HashSet<(usize, usize)>,
+) -> Vec<bool> {
+    let mut keep = vec![true; triangles.len()];
+    let mut visited = vec![false; triangles.len()];
+
+    let mut edge_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (i, tri) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let a = tri[k];
+            let b = tri[(k + 1) % 3];
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_triangles.entry(key).or_default().push(i);
+        }
+    }
+
+    let mut stack = Vec::new();
+    for (i, tri) in triangles.iter().enumerate() {
+        let centroid = Point::new(
+            (points[tri[0]].x + points[tri[1]].x + points[tri[2]].x) / 3.0,
+            (points[tri[0]].y + points[tri[1]].y + points[tri[2]].y) / 3.0,
+        );
+        let outside_outer = !point_in_polygon(&centroid, outer);
+        let inside_a_hole = holes.iter().any(|hole| point_in_polygon(&centroid, hole));
+
+        if outside_outer || inside_a_hole {
+            visited[i] = true;
+            keep[i] = false;
+            stack.push(i);
+        }
+    }
+
+    while let Some(i) = stack.pop() {
+        let tri = triangles[i];
+        for k in 0..3 {
+            let a = tri[k];
+            let b = tri[(k + 1) % 3];
+            let key = if a < b { (a, b) } else { (b, a) };
+
+            if constraints.contains(&key) {
+                continue;
+            }
+
+            if let Some(neighbors) = edge_triangles.get(&key) {
+                for &j in neighbors {
+                    if j != i && !visited[j] {
+                        visited[j] = true;
+                        keep[j] = false;
+                        stack.push(j);
+                    }
+                }
+            }
+        }
+    }
+
+    keep
+}
+
 #[wasm_bindgen]
 pub struct Mesher {
     points: Vec<Point>,
     triangles: Vec<Triangle>,
     boundary_points: HashSet<usize>,
+    holes: Vec<Vec<Point>>,
 }
 
 #[wasm_bindgen]
@@ -133,6 +601,7 @@ impl Mesher {
             points: Vec::new(),
             triangles: Vec::new(),
             boundary_points: HashSet::new(),
+            holes: Vec::new(),
         }
     }
 
@@ -141,12 +610,13 @@ impl Mesher {
         self.points.clear();
         self.triangles.clear();
         self.boundary_points.clear();
+        self.holes.clear();
     }
 
     #[wasm_bindgen]
     pub fn add_polygon(&mut self, polygon_points: &[f64]) {
         self.clear();
-        
+
         for i in (0..polygon_points.len()).step_by(2) {
             if i + 1 < polygon_points.len() {
                 let point = Point::new(polygon_points[i], polygon_points[i + 1]);
@@ -156,25 +626,64 @@ impl Mesher {
         }
     }
 
+    /// Registers an interior hole loop; its boundary is recovered as constrained mesh edges
+    /// and the region it encloses is excluded from the triangulation.
+    #[wasm_bindgen]
+    pub fn add_hole(&mut self, hole_points: &[f64]) {
+        let mut hole = Vec::new();
+        for i in (0..hole_points.len()).step_by(2) {
+            if i + 1 < hole_points.len() {
+                hole.push(Point::new(hole_points[i], hole_points[i + 1]));
+            }
+        }
+        if hole.len() >= 3 {
+            self.holes.push(hole);
+        }
+    }
+
     fn is_point_in_polygon(&self, point: &Point, polygon: &[Point]) -> bool {
-        let x = point.x;
-        let y = point.y;
-        let mut inside = false;
-
-        let mut j = polygon.len() - 1;
-        for i in 0..polygon.len() {
-            let xi = polygon[i].x;
-            let yi = polygon[i].y;
-            let xj = polygon[j].x;
-            let yj = polygon[j].y;
-
-            if ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi) {
-                inside = !inside;
+        point_in_polygon(point, polygon)
+    }
+
+    /// Meshes the region `values[y*width+x] < iso` of a sampled scalar field via marching
+    /// squares: the extracted contour loops are densified and triangulated through the same
+    /// constrained Delaunay path as [`Mesher::generate_mesh`]. The loop enclosing the largest
+    /// area becomes the outer boundary; every other loop is registered as a hole, so a field
+    /// with more than one disjoint `< iso` region only meshes the largest one (matching this
+    /// struct's single-outer-loop-plus-holes model).
+    #[wasm_bindgen]
+    pub fn generate_mesh_from_field(
+        &mut self,
+        values: &[f64],
+        width: usize,
+        height: usize,
+        origin_x: f64,
+        origin_y: f64,
+        cell: f64,
+        iso: f64,
+        density: f64,
+    ) -> bool {
+        let mut loops = march_squares(values, width, height, origin_x, origin_y, cell, iso);
+        if loops.is_empty() {
+            return false;
+        }
+
+        loops.sort_by(|a, b| polygon_area(b).partial_cmp(&polygon_area(a)).unwrap());
+        let outer = loops.remove(0);
+        if outer.len() < 3 {
+            return false;
+        }
+
+        self.clear();
+        self.points = outer;
+        self.boundary_points = (0..self.points.len()).collect();
+        for hole in loops {
+            if hole.len() >= 3 {
+                self.holes.push(hole);
             }
-            j = i;
         }
 
-        inside
+        self.generate_mesh(density)
     }
 
     #[wasm_bindgen]
@@ -183,47 +692,44 @@ impl Mesher {
             return false;
         }
 
-        let polygon = self.points.clone();
+        let outer = self.points.clone();
         self.densify_boundary(density);
-        self.add_interior_points(density, &polygon);
-        self.triangulate(&polygon);
+
+        let hole_loops: Vec<Vec<Point>> = self.holes.iter()
+            .map(|hole| densify_loop(hole, density))
+            .collect();
+
+        let mut constraints: HashSet<(usize, usize)> = HashSet::new();
+        let boundary_count = self.points.len();
+        for i in 0..boundary_count {
+            constraints.insert(canonical_edge(i, (i + 1) % boundary_count));
+        }
+
+        for hole in &hole_loops {
+            let start = self.points.len();
+            self.points.extend(hole.iter().cloned());
+            let end = self.points.len();
+            for i in start..end {
+                let next = if i + 1 == end { start } else { i + 1 };
+                constraints.insert(canonical_edge(i, next));
+            }
+        }
+
+        self.add_interior_points(density, &outer, &hole_loops);
+        self.triangulate(&outer, &hole_loops, &constraints);
 
         true
     }
 
     fn densify_boundary(&mut self, density: f64) {
-        let original_count = self.points.len();
-        let mut new_points = Vec::new();
-        
-        for i in 0..original_count {
-            let current = self.points[i];
-            let next = self.points[(i + 1) % original_count];
-            
-            let edge_length = current.distance_to(&next);
-            let num_segments = (edge_length / density).ceil() as usize;
-            
-            new_points.push(current);
-            
-            if num_segments > 1 {
-                for j in 1..num_segments {
-                    let t = j as f64 / num_segments as f64;
-                    let x = current.x + t * (next.x - current.x);
-                    let y = current.y + t * (next.y - current.y);
-                    new_points.push(Point::new(x, y));
-                    self.boundary_points.insert(self.points.len() + new_points.len() - 1);
-                }
-            }
-        }
-        
-        self.points = new_points;
-        // Update boundary points indices
+        self.points = densify_loop(&self.points, density);
         self.boundary_points.clear();
         for i in 0..self.points.len() {
             self.boundary_points.insert(i);
         }
     }
 
-    fn add_interior_points(&mut self, density: f64, polygon: &[Point]) {
+    fn add_interior_points(&mut self, density: f64, polygon: &[Point], holes: &[Vec<Point>]) {
         let mut min_x = f64::INFINITY;
         let mut min_y = f64::INFINITY;
         let mut max_x = f64::NEG_INFINITY;
@@ -236,12 +742,15 @@ impl Mesher {
             max_y = max_y.max(point.y);
         }
 
+        let loop_point_count = self.points.len();
         let mut x = min_x;
         while x < max_x {
             let mut y = min_y;
             while y < max_y {
                 let point = Point::new(x, y);
-                if self.is_point_in_polygon(&point, polygon) {
+                let inside_a_hole = holes.iter().any(|hole| self.is_point_in_polygon(&point, hole));
+                let on_loop_point = self.points[..loop_point_count].iter().any(|p| p.distance_to(&point) < 1e-9);
+                if self.is_point_in_polygon(&point, polygon) && !inside_a_hole && !on_loop_point {
                     self.points.push(point);
                 }
                 y += density;
@@ -250,33 +759,40 @@ impl Mesher {
         }
     }
 
-    fn triangulate(&mut self, polygon: &[Point]) {
+    /// Builds the unconstrained Delaunay triangulation, recovers every boundary/hole segment
+    /// by flipping the edges it crosses, then keeps only the triangles a flood fill from
+    /// outside the domain (and from inside each hole) never reaches.
+    fn triangulate(&mut self, outer: &[Point], holes: &[Vec<Point>], constraints: &HashSet<(usize, usize)>) {
         if self.points.len() < 3 {
             return;
         }
 
-        // Use delaunator for Delaunay triangulation
         let delaunay_points: Vec<delaunator::Point> = self.points.iter()
             .map(|p| delaunator::Point { x: p.x, y: p.y })
             .collect();
 
         let triangulation = delaunator::triangulate(&delaunay_points);
-        
-        self.triangles.clear();
-        
-        // Filter triangles to only include those inside the polygon
+
+        let mut raw_triangles: Vec<[usize; 3]> = Vec::new();
         for i in (0..triangulation.triangles.len()).step_by(3) {
-            let tri = Triangle::new(
+            raw_triangles.push([
                 triangulation.triangles[i],
                 triangulation.triangles[i + 1],
                 triangulation.triangles[i + 2],
-            );
-            
-            let center = tri.center(&self.points);
-            if self.is_point_in_polygon(&center, polygon) {
-                self.triangles.push(tri);
-            }
+            ]);
         }
+
+        for &(a, b) in constraints {
+            recover_segment(&self.points, &mut raw_triangles, a, b);
+        }
+
+        let keep = classify_inside(&self.points, &raw_triangles, outer, holes, constraints);
+
+        self.triangles = raw_triangles.into_iter()
+            .zip(keep)
+            .filter(|&(_, keep)| keep)
+            .map(|(tri, _)| Triangle::new(tri[0], tri[1], tri[2]))
+            .collect();
     }
 
     #[wasm_bindgen]
@@ -298,7 +814,8 @@ impl Mesher {
             if let Some(circumcenter) = triangle.circumcenter(&self.points) {
                 if self.is_point_in_polygon(&circumcenter, &polygon) {
                     self.points.push(circumcenter);
-                    self.triangulate(&polygon);
+                    let holes = self.holes.clone();
+                    self.triangulate(&polygon, &holes, &HashSet::new());
                     iterations += 1;
                 } else {
                     break;
@@ -307,7 +824,7 @@ impl Mesher {
                 break;
             }
         }
-        
+
         iterations
     }
 
@@ -395,7 +912,8 @@ impl Mesher {
             }
 
             self.points = new_points;
-            self.triangulate(&polygon);
+            let holes = self.holes.clone();
+            self.triangulate(&polygon, &holes, &HashSet::new());
 
             if moved_count == 0 {
                 break;
@@ -419,6 +937,64 @@ impl Mesher {
         mesh_data.to_string()
     }
 
+    /// Extrudes every 2D triangle into a prism of `thickness` and writes it as binary STL.
+    /// Passing `thickness == 0.0` instead emits a flat, zero-volume planar STL.
+    #[wasm_bindgen]
+    pub fn export_stl(&self, thickness: f64) -> Vec<u8> {
+        let facet_count: usize = if thickness == 0.0 {
+            self.triangles.len()
+        } else {
+            self.triangles.len() * 8
+        };
+
+        let mut buffer = Vec::with_capacity(84 + facet_count * 50);
+        buffer.extend_from_slice(&[0u8; 80]);
+        buffer.extend_from_slice(&(facet_count as u32).to_le_bytes());
+
+        for tri in &self.triangles {
+            let p0 = self.points[tri.indices[0]];
+            let p1 = self.points[tri.indices[1]];
+            let p2 = self.points[tri.indices[2]];
+
+            if thickness == 0.0 {
+                write_stl_facet(&mut buffer, (p0.x, p0.y, 0.0), (p1.x, p1.y, 0.0), (p2.x, p2.y, 0.0));
+                continue;
+            }
+
+            write_stl_facet(&mut buffer, (p0.x, p0.y, 0.0), (p2.x, p2.y, 0.0), (p1.x, p1.y, 0.0));
+            write_stl_facet(&mut buffer, (p0.x, p0.y, thickness), (p1.x, p1.y, thickness), (p2.x, p2.y, thickness));
+
+            for (a, b) in [(p0, p1), (p1, p2), (p2, p0)] {
+                write_stl_facet(&mut buffer, (a.x, a.y, 0.0), (b.x, b.y, 0.0), (b.x, b.y, thickness));
+                write_stl_facet(&mut buffer, (a.x, a.y, 0.0), (b.x, b.y, thickness), (a.x, a.y, thickness));
+            }
+        }
+
+        buffer
+    }
+
+    /// Writes the mesh as a flat (z=0) Wavefront OBJ: `v x y 0` per point and 1-based `f i j k`
+    /// faces per triangle.
+    #[wasm_bindgen]
+    pub fn export_obj(&self) -> String {
+        let mut obj = String::new();
+
+        for p in &self.points {
+            obj.push_str(&format!("v {} {} 0\n", p.x, p.y));
+        }
+
+        for tri in &self.triangles {
+            obj.push_str(&format!(
+                "f {} {} {}\n",
+                tri.indices[0] + 1,
+                tri.indices[1] + 1,
+                tri.indices[2] + 1,
+            ));
+        }
+
+        obj
+    }
+
     #[wasm_bindgen]
     pub fn get_triangle_count(&self) -> usize {
         self.triangles.len()
@@ -481,4 +1057,50 @@ impl Mesher {
 #[wasm_bindgen(start)]
 pub fn main() {
     console_log!("Rust mesher loaded!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 10x10 square with a 2x2 hole centered at (5, 5) should mesh the annulus between them:
+    /// every hole-boundary edge must survive segment recovery as an actual mesh edge, and no
+    /// kept triangle's centroid may fall inside the hole.
+    #[test]
+    fn generate_mesh_recovers_hole_boundary_and_excludes_its_interior() {
+        let mut mesher = Mesher::new();
+        mesher.add_polygon(&[0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0]);
+        mesher.add_hole(&[4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0]);
+
+        assert!(mesher.generate_mesh(1.0));
+        assert!(!mesher.triangles.is_empty());
+
+        for triangle in &mesher.triangles {
+            let centroid = triangle.center(&mesher.points);
+            assert!(!mesher.is_point_in_polygon(&centroid, &mesher.holes[0]));
+        }
+
+        // `generate_mesh` densifies the hole loop at `density` before recovering it, so the
+        // constrained hole edges are the densified loop's sub-segments.
+        let hole = densify_loop(&mesher.holes[0], 1.0);
+        let hole_point_indices: Vec<usize> = hole.iter()
+            .map(|hole_point| {
+                mesher.points.iter().position(|p| p.distance_to(hole_point) < 1e-9)
+                    .expect("hole vertex should have been carried into the point set")
+            })
+            .collect();
+
+        let mesh_edges: HashSet<(usize, usize)> = mesher.triangles.iter()
+            .flat_map(|t| {
+                let [a, b, c] = t.indices;
+                vec![canonical_edge(a, b), canonical_edge(b, c), canonical_edge(c, a)]
+            })
+            .collect();
+
+        for i in 0..hole_point_indices.len() {
+            let a = hole_point_indices[i];
+            let b = hole_point_indices[(i + 1) % hole_point_indices.len()];
+            assert!(mesh_edges.contains(&canonical_edge(a, b)), "hole edge {a}-{b} missing from mesh");
+        }
+    }
 }
\ No newline at end of file
@@ -2,10 +2,18 @@ use std::env;
 use std::io::{self, Read};
 use serde_json;
 
+mod annealing;
+mod delaunay;
+mod elements;
 mod geometry;
+mod mesh;
 mod mesher;
+mod obj_io;
+mod predicates;
+mod spatial_index;
 
-use geometry::Point;
+use geometry::{convex_hull, Point, SizingField};
+use mesh::QualityReport;
 use mesher::MeshCore;
 
 #[derive(serde::Deserialize)]
@@ -16,12 +24,75 @@ struct CliInput {
     refine_threshold: Option<f64>,
     refine_iterations: Option<usize>,
     smooth_iterations: Option<usize>,
+    /// `"delaunay"` (default) runs the existing density/refine/smooth pipeline; `"ear_clip"`
+    /// triangulates `geometry.points`/`geometry.holes` exactly via ear clipping, with no Steiner
+    /// points and no refinement - `density`/`refine_*`/`smooth_iterations` are ignored. `"cdt"`
+    /// runs `delaunay::DelaunayTriangulator::triangulate_constrained_refined` instead: a
+    /// half-edge constrained Delaunay triangulation of `geometry.points` followed by Ruppert
+    /// refinement toward `refine_threshold`/`density`; it takes no holes and ignores
+    /// `refine_metric`/`smooth_iterations`. `"anneal"` runs
+    /// `annealing::GridAnnealingMeshGenerator` instead: simulated-annealing mesh generation
+    /// toward `refine_threshold` (quality threshold, default `0.5`) over up to
+    /// `refine_iterations` (default `10000`) moves at element size `density`; ignores
+    /// `refine_metric`/`smooth_iterations`/`sizing`.
+    method: Option<String>,
+    /// Spatially-varying target element size, graded across the domain instead of the flat
+    /// `density`. Used only by the default `"delaunay"` method: `"ear_clip"` takes no Steiner
+    /// points at all, and `"cdt"`/`"anneal"` ignore it.
+    sizing: Option<SizingFieldInput>,
+    /// File path to additionally write the generated mesh to, in `export_format`. Skipped if
+    /// `None`; errors are reported to stderr without failing the JSON output.
+    export_path: Option<String>,
+    /// `"obj"` (default) writes a flat Wavefront OBJ via `obj_io::write_obj`; `"stl"` extrudes the
+    /// mesh by `export_thickness` (default `0.0`, a flat planar shell) and writes binary STL via
+    /// `obj_io::export_to_stl`.
+    export_format: Option<String>,
+    export_thickness: Option<f64>,
 }
 
 #[derive(serde::Deserialize)]
 struct Geometry {
     points: Vec<Point>,
     name: Option<String>,
+    holes: Option<Vec<Vec<Point>>>,
+}
+
+#[derive(serde::Deserialize)]
+struct SizingFieldInput {
+    /// `"boundary_graded"` derives a grid from `h_near`/`h_far`/`falloff`/`resolution`; anything
+    /// else (the default) takes an explicit `min`/`max`/`cols`/`rows`/`values` grid as-is.
+    preset: Option<String>,
+    min: Option<Point>,
+    max: Option<Point>,
+    cols: Option<usize>,
+    rows: Option<usize>,
+    values: Option<Vec<f64>>,
+    h_near: Option<f64>,
+    h_far: Option<f64>,
+    falloff: Option<f64>,
+    resolution: Option<usize>,
+}
+
+/// Builds a `SizingField` from the CLI's JSON description, using `boundary` (the outer polygon)
+/// as the reference shape for the `"boundary_graded"` preset.
+fn build_sizing_field(input: &SizingFieldInput, boundary: &[Point]) -> SizingField {
+    if input.preset.as_deref() == Some("boundary_graded") {
+        SizingField::boundary_graded(
+            boundary,
+            input.h_near.unwrap_or(0.05),
+            input.h_far.unwrap_or(0.5),
+            input.falloff.unwrap_or(1.0),
+            input.resolution.unwrap_or(20),
+        )
+    } else {
+        SizingField {
+            min: input.min.unwrap_or(Point::new(0.0, 0.0)),
+            max: input.max.unwrap_or(Point::new(1.0, 1.0)),
+            cols: input.cols.unwrap_or(1),
+            rows: input.rows.unwrap_or(1),
+            values: input.values.clone().unwrap_or_else(|| vec![0.1]),
+        }
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -37,6 +108,57 @@ struct MeshStats {
     triangle_count: usize,
     avg_angle_quality: f64,
     avg_aspect_quality: f64,
+    boundary: Vec<Point>,
+    /// Indices into `MeshOutput.triangles` of every triangle touching the domain boundary (outer
+    /// hull or a hole rim) - lets downstream tools highlight or specially-handle the boundary
+    /// layer without recomputing it from the triangle list themselves.
+    boundary_triangle_indices: Vec<usize>,
+    /// One-pass Jacobian/angle-histogram/worst-triangle summary, computed via `mesh::Mesh` so the
+    /// CLI can emit a full quality report alongside the simpler `avg_*_quality` scalars above.
+    quality_report: QualityReport,
+}
+
+/// `mesher.boundary_triangles()`, sorted into a stable, serializable order.
+fn boundary_triangle_indices(mesher: &MeshCore) -> Vec<usize> {
+    let mut indices: Vec<usize> = mesher.boundary_triangles().into_iter().collect();
+    indices.sort_unstable();
+    indices
+}
+
+/// Rebuilds `mesher`'s points/triangles as a `mesh::Mesh` - `MeshCore` doesn't itself track the
+/// per-triangle circumcircle/Jacobian data `quality_report`/export need.
+fn mesher_to_mesh(mesher: &MeshCore) -> mesh::Mesh {
+    let triangles: Vec<elements::Triangle> = mesher.triangles.iter()
+        .map(|t| elements::Triangle::new(t.indices, &mesher.points))
+        .collect();
+    mesh::Mesh::new(mesher.points.clone(), triangles)
+}
+
+/// Runs `mesher_to_mesh`'s one-pass quality summary (via the thread-parallel Jacobian reduction
+/// once the mesh is large enough to matter).
+fn quality_report(mesher: &MeshCore) -> QualityReport {
+    mesher_to_mesh(mesher).quality_report(true)
+}
+
+/// Writes the generated mesh to `path` in `format` ("obj" or "stl", defaulting to "obj"), logging
+/// any I/O failure to stderr without aborting the rest of `process_json_input`.
+fn export_mesh(mesher: &MeshCore, path: &str, format: Option<&str>, thickness: f64) {
+    let mesh = mesher_to_mesh(mesher);
+
+    let result = match format {
+        Some("stl") => std::fs::write(path, obj_io::export_to_stl(&mesh, thickness)),
+        _ => std::fs::write(path, obj_io::write_obj(&mesh)),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error writing mesh export to '{}': {}", path, e);
+    }
+}
+
+/// Domain boundary for `MeshStats.boundary`: the convex hull of the generated mesh's points, in
+/// CCW order.
+fn mesh_boundary(mesher: &MeshCore) -> Vec<Point> {
+    convex_hull(&mesher.points).into_iter().map(|i| mesher.points[i]).collect()
 }
 
 fn main() {
@@ -59,6 +181,14 @@ fn main() {
         }
         "json-stdin" => run_json_stdin(),
         "interactive" => run_interactive(),
+        "obj" => {
+            if args.len() < 3 {
+                eprintln!("Error: obj mode requires a filename");
+                print_usage();
+                return;
+            }
+            run_obj_file(&args[2]);
+        }
         _ => {
             eprintln!("Error: Unknown command '{}'", args[1]);
             print_usage();
@@ -73,6 +203,7 @@ fn print_usage() {
     println!("  mesher json <file>             - Process JSON from file");
     println!("  mesher json-stdin              - Process JSON from stdin");
     println!("  mesher interactive             - Interactive mode");
+    println!("  mesher obj <file>               - Load a Wavefront OBJ mesh and print its quality report");
     println!();
     println!("JSON Input Format:");
     println!("{{");
@@ -110,10 +241,13 @@ fn run_test() {
         let stats = MeshStats {
             point_count: mesher.points.len(),
             triangle_count: mesher.triangles.len(),
-            avg_angle_quality: mesher.get_average_quality("angle"),
-            avg_aspect_quality: mesher.get_average_quality("aspect"),
+            avg_angle_quality: mesher.get_average_quality_parallel("angle", true),
+            avg_aspect_quality: mesher.get_average_quality_parallel("aspect", true),
+            boundary: mesh_boundary(&mesher),
+            boundary_triangle_indices: boundary_triangle_indices(&mesher),
+            quality_report: quality_report(&mesher),
         };
-        
+
         println!("Mesh Statistics:");
         println!("  Points: {}", stats.point_count);
         println!("  Triangles: {}", stats.triangle_count);
@@ -131,6 +265,58 @@ fn run_json_file(filename: &str) {
     }
 }
 
+/// Loads a Wavefront OBJ mesh via `obj_io::read_obj` and prints the same `MeshOutput` JSON the
+/// `json`/`json-stdin` commands do, with no density/refine/smooth pipeline applied - the mesh is
+/// taken exactly as parsed.
+fn run_obj_file(filename: &str) {
+    let contents = match std::fs::read_to_string(filename) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error reading file '{}': {}", filename, e);
+            return;
+        }
+    };
+
+    let parsed = match obj_io::read_obj(&contents) {
+        Ok(mesh) => mesh,
+        Err(e) => {
+            eprintln!("Error parsing OBJ: {}", e);
+            return;
+        }
+    };
+
+    let boundary_count = parsed.vertices.len();
+    let mut mesher = MeshCore::new();
+    mesher.boundary_points = (0..boundary_count).collect();
+    mesher.points = parsed.vertices;
+    mesher.triangles = parsed.triangle_indices.into_iter()
+        .map(|indices| geometry::Triangle { indices })
+        .collect();
+
+    let triangles_data: Vec<[usize; 3]> = mesher.triangles.iter()
+        .map(|t| t.indices)
+        .collect();
+
+    let output = MeshOutput {
+        points: mesher.points.clone(),
+        triangles: triangles_data,
+        stats: MeshStats {
+            point_count: mesher.points.len(),
+            triangle_count: mesher.triangles.len(),
+            avg_angle_quality: mesher.get_average_quality_parallel("angle", true),
+            avg_aspect_quality: mesher.get_average_quality_parallel("aspect", true),
+            boundary: mesh_boundary(&mesher),
+            boundary_triangle_indices: boundary_triangle_indices(&mesher),
+            quality_report: quality_report(&mesher),
+        },
+    };
+
+    match serde_json::to_string_pretty(&output) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Error serializing output: {}", e),
+    }
+}
+
 fn run_json_stdin() {
     let mut input = String::new();
     match io::stdin().read_to_string(&mut input) {
@@ -148,38 +334,119 @@ fn process_json_input(input: &str) {
         }
     };
     
-    let mut mesher = MeshCore::new();
-    mesher.add_polygon_from_points(&cli_input.geometry.points);
-    
-    let density = cli_input.density.unwrap_or(0.1);
-    
-    if !mesher.generate_mesh(density) {
-        eprintln!("Failed to generate mesh");
-        return;
-    }
-    
-    if let Some(metric) = cli_input.refine_metric {
-        let threshold = cli_input.refine_threshold.unwrap_or(if metric == "angle" { 20.0 } else { 2.0 });
-        let iterations = cli_input.refine_iterations.unwrap_or(100);
-        mesher.refine_mesh(&metric, threshold, iterations);
-    }
-    
-    if let Some(smooth_iters) = cli_input.smooth_iterations {
-        mesher.smooth_mesh(smooth_iters);
+    let mesher = if cli_input.method.as_deref() == Some("ear_clip") {
+        let holes = cli_input.geometry.holes.clone().unwrap_or_default();
+        let (points, triangles) = geometry::ear_clip_with_holes(&cli_input.geometry.points, &holes);
+
+        let mut mesher = MeshCore::new();
+        mesher.boundary_points = (0..points.len()).collect();
+        mesher.points = points;
+        mesher.triangles = triangles.into_iter()
+            .map(|indices| geometry::Triangle { indices })
+            .collect();
+        mesher
+    } else if cli_input.method.as_deref() == Some("cdt") {
+        let boundary_count = cli_input.geometry.points.len();
+        let max_area = cli_input.density.unwrap_or(0.1);
+        let min_angle = cli_input.refine_threshold.unwrap_or(20.0);
+
+        let mesh = match delaunay::DelaunayTriangulator::triangulate_constrained_refined(
+            &cli_input.geometry.points,
+            max_area,
+            min_angle,
+        ) {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                eprintln!("Failed to generate mesh: {}", e);
+                return;
+            }
+        };
+
+        let mut mesher = MeshCore::new();
+        mesher.boundary_points = (0..boundary_count).collect();
+        mesher.points = mesh.vertices;
+        mesher.triangles = mesh.triangle_indices.into_iter()
+            .map(|indices| geometry::Triangle { indices })
+            .collect();
+        mesher
+    } else if cli_input.method.as_deref() == Some("anneal") {
+        let holes = cli_input.geometry.holes.clone().unwrap_or_default();
+        let quality_threshold = cli_input.refine_threshold.unwrap_or(0.5);
+        let target_area = cli_input.density.unwrap_or(0.1);
+        let max_iterations = cli_input.refine_iterations.unwrap_or(10000) as u32;
+
+        let mut generator = if holes.is_empty() {
+            annealing::GridAnnealingMeshGenerator::new(cli_input.geometry.points.clone(), quality_threshold)
+        } else {
+            annealing::GridAnnealingMeshGenerator::with_holes(cli_input.geometry.points.clone(), holes, quality_threshold)
+        };
+
+        let mesh = match generator.generate_mesh_with_iterations(target_area, max_iterations) {
+            Ok(mesh) => mesh,
+            Err(e) => {
+                eprintln!("Failed to generate mesh: {}", e);
+                return;
+            }
+        };
+
+        let mut mesher = MeshCore::new();
+        mesher.points = mesh.vertices;
+        mesher.boundary_points = geometry::convex_hull(&mesher.points).into_iter().collect();
+        mesher.triangles = mesh.triangle_indices.into_iter()
+            .map(|indices| geometry::Triangle { indices })
+            .collect();
+        mesher
+    } else {
+        let mut mesher = MeshCore::new();
+        match &cli_input.geometry.holes {
+            Some(holes) if !holes.is_empty() => {
+                mesher.add_polygon_with_holes(&cli_input.geometry.points, holes);
+            }
+            _ => mesher.add_polygon_from_points(&cli_input.geometry.points),
+        }
+
+        if let Some(sizing_input) = &cli_input.sizing {
+            mesher.sizing = Some(build_sizing_field(sizing_input, &cli_input.geometry.points));
+        }
+
+        let density = cli_input.density.unwrap_or(0.1);
+
+        if !mesher.generate_mesh(density) {
+            eprintln!("Failed to generate mesh");
+            return;
+        }
+
+        if let Some(metric) = cli_input.refine_metric {
+            let threshold = cli_input.refine_threshold.unwrap_or(if metric == "angle" { 20.0 } else { 2.0 });
+            let iterations = cli_input.refine_iterations.unwrap_or(100);
+            mesher.refine_mesh(&metric, threshold, iterations);
+        }
+
+        if let Some(smooth_iters) = cli_input.smooth_iterations {
+            mesher.smooth_mesh(smooth_iters);
+        }
+        mesher
+    };
+
+    if let Some(path) = &cli_input.export_path {
+        export_mesh(&mesher, path, cli_input.export_format.as_deref(), cli_input.export_thickness.unwrap_or(0.0));
     }
-    
+
     let triangles_data: Vec<[usize; 3]> = mesher.triangles.iter()
         .map(|t| t.indices)
         .collect();
-    
+
     let output = MeshOutput {
         points: mesher.points.clone(),
         triangles: triangles_data,
         stats: MeshStats {
             point_count: mesher.points.len(),
             triangle_count: mesher.triangles.len(),
-            avg_angle_quality: mesher.get_average_quality("angle"),
-            avg_aspect_quality: mesher.get_average_quality("aspect"),
+            avg_angle_quality: mesher.get_average_quality_parallel("angle", true),
+            avg_aspect_quality: mesher.get_average_quality_parallel("aspect", true),
+            boundary: mesh_boundary(&mesher),
+            boundary_triangle_indices: boundary_triangle_indices(&mesher),
+            quality_report: quality_report(&mesher),
         },
     };
     
@@ -253,8 +520,11 @@ fn run_interactive() {
             stats: MeshStats {
                 point_count: mesher.points.len(),
                 triangle_count: mesher.triangles.len(),
-                avg_angle_quality: mesher.get_average_quality("angle"),
-                avg_aspect_quality: mesher.get_average_quality("aspect"),
+                avg_angle_quality: mesher.get_average_quality_parallel("angle", true),
+                avg_aspect_quality: mesher.get_average_quality_parallel("aspect", true),
+                boundary: mesh_boundary(&mesher),
+                boundary_triangle_indices: boundary_triangle_indices(&mesher),
+                quality_report: quality_report(&mesher),
             },
         };
         
@@ -1,6 +1,6 @@
 use std::f64;
 use serde::{Deserialize, Serialize};
-use crate::geometry::Point;
+use crate::geometry::{Affine2, Point};
 use crate::elements::{Triangle, Quad};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +12,97 @@ pub struct Mesh {
     pub quad_indices: Option<Vec<[usize; 4]>>,
 }
 
+/// Below this many combined triangles+quads, spinning up `reduce_parallel`'s worker threads costs
+/// more than just folding serially would - matches the small-input skip `spatial_index`'s indexes
+/// use for the same reason.
+const PARALLEL_ELEMENT_THRESHOLD: usize = 10_000;
+
+const ANGLE_HISTOGRAM_BUCKETS: usize = 9;
+const ANGLE_BUCKET_WIDTH_DEGREES: f64 = 10.0;
+const WORST_ELEMENT_COUNT: usize = 5;
+
+/// Min/max/sum/count accumulator for a quality scalar (Jacobian, min-angle, ...). Associative and
+/// commutative by construction - `min`/`max` exactly so, `sum` only up to floating-point
+/// reordering - so elements can be folded in any grouping (per-thread chunks, then combined) and
+/// still land on the same min/max, with `avg` differing from a strictly serial fold only in its
+/// last bit or so.
+#[derive(Clone, Copy)]
+struct QualityAcc {
+    min: f64,
+    max: f64,
+    sum: f64,
+    count: usize,
+}
+
+impl QualityAcc {
+    const IDENTITY: QualityAcc = QualityAcc { min: f64::INFINITY, max: f64::NEG_INFINITY, sum: 0.0, count: 0 };
+
+    fn push(mut self, value: f64) -> QualityAcc {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.sum += value;
+        self.count += 1;
+        self
+    }
+
+    fn combine(self, other: QualityAcc) -> QualityAcc {
+        QualityAcc {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+            sum: self.sum + other.sum,
+            count: self.count + other.count,
+        }
+    }
+
+    fn finish(self) -> (f64, f64, f64) {
+        let avg = if self.count > 0 { self.sum / self.count as f64 } else { 0.0 };
+        (self.min, self.max, avg)
+    }
+}
+
+/// Number of worker threads to split parallel reductions across. Uses `std::thread` rather than
+/// a crate dependency so `Mesh` stays dependency-light for callers who never ask for the
+/// parallel path - there's nothing to add to a manifest that doesn't exist yet for small inputs.
+fn worker_count() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Folds `f` over `items` using `worker_count()` scoped threads, each reducing its own chunk with
+/// `QualityAcc::push` before the per-chunk accumulators are combined - the data-parallel
+/// counterpart to `items.iter().fold(QualityAcc::IDENTITY, |acc, item| acc.push(f(item)))`.
+fn reduce_parallel<T, F>(items: &[T], workers: usize, f: F) -> QualityAcc
+where
+    T: Sync,
+    F: Fn(&T) -> f64 + Sync,
+{
+    if items.is_empty() {
+        return QualityAcc::IDENTITY;
+    }
+
+    let chunk_size = (items.len() / workers.max(1)).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items.chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().fold(QualityAcc::IDENTITY, |acc, item| acc.push(f(item)))))
+            .collect();
+        handles.into_iter()
+            .map(|handle| handle.join().unwrap())
+            .fold(QualityAcc::IDENTITY, QualityAcc::combine)
+    })
+}
+
+/// One-pass quality summary of a mesh's triangles: Jacobian min/max/avg, a 10-degree-bucketed
+/// min-angle histogram (quads have no single min-angle measure, so only triangles count here),
+/// and the indices of the `WORST_ELEMENT_COUNT` skinniest triangles - everything `quality_report`
+/// needs from one call instead of one call per statistic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityReport {
+    pub min_jacobian: f64,
+    pub max_jacobian: f64,
+    pub avg_jacobian: f64,
+    pub angle_histogram: [usize; ANGLE_HISTOGRAM_BUCKETS],
+    pub worst_triangle_indices: Vec<usize>,
+}
+
 impl Mesh {
     pub fn new(vertices: Vec<Point>, triangles: Vec<Triangle>) -> Self {
         let triangle_indices: Vec<[usize; 3]> = triangles.iter().map(|t| t.vertices).collect();
@@ -54,6 +145,37 @@ impl Mesh {
         Ok(())
     }
 
+    /// Same outcome as `validate_jacobians`, but checks for *any* non-positive Jacobian across
+    /// `worker_count()` threads first. On a valid mesh (the common case) that's the whole job,
+    /// done in parallel; if something invalid turns up, re-runs the plain serial scan so the
+    /// reported index and message exactly match what `validate_jacobians` would have said -
+    /// which thread happens to find a bad element first isn't deterministic, but the first bad
+    /// element in index order always is.
+    pub fn validate_jacobians_parallel(&self, parallel: bool) -> Result<(), String> {
+        let element_count = self.triangle_indices.len() + self.quad_indices.as_ref().map_or(0, |q| q.len());
+        if !parallel || element_count < PARALLEL_ELEMENT_THRESHOLD {
+            return self.validate_jacobians();
+        }
+
+        let workers = worker_count();
+        let tri_acc = reduce_parallel(&self.triangle_indices, workers, |verts| {
+            let jacobian = Triangle::new(*verts, &self.vertices).jacobian(&self.vertices);
+            if jacobian <= 0.0 { 1.0 } else { 0.0 }
+        });
+        let quad_acc = match &self.quad_indices {
+            Some(quad_indices) => reduce_parallel(quad_indices, workers, |verts| {
+                if Quad::new(*verts).min_jacobian(&self.vertices) <= 0.0 { 1.0 } else { 0.0 }
+            }),
+            None => QualityAcc::IDENTITY,
+        };
+
+        if tri_acc.sum + quad_acc.sum > 0.0 {
+            self.validate_jacobians()
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn get_jacobian_stats(&self) -> (f64, f64, f64) {
         let mut min_jac = f64::INFINITY;
         let mut max_jac = f64::NEG_INFINITY;
@@ -83,7 +205,86 @@ impl Mesh {
         let avg_jac = if count > 0 { sum_jac / count as f64 } else { 0.0 };
         (min_jac, max_jac, avg_jac)
     }
-    
+
+    /// Same result as `get_jacobian_stats` (min/max exactly, avg up to float-reordering), but
+    /// splits triangles and quads across `worker_count()` threads via `reduce_parallel` instead
+    /// of folding everything in one serial pass. Falls back to the serial path below
+    /// `PARALLEL_ELEMENT_THRESHOLD` elements or when `parallel` is false, since spinning up
+    /// threads for a small mesh costs more than it saves.
+    pub fn get_jacobian_stats_parallel(&self, parallel: bool) -> (f64, f64, f64) {
+        let element_count = self.triangle_indices.len() + self.quad_indices.as_ref().map_or(0, |q| q.len());
+        if !parallel || element_count < PARALLEL_ELEMENT_THRESHOLD {
+            return self.get_jacobian_stats();
+        }
+
+        let workers = worker_count();
+        let tri_acc = reduce_parallel(&self.triangle_indices, workers, |verts| {
+            Triangle::new(*verts, &self.vertices).jacobian(&self.vertices)
+        });
+        let quad_acc = match &self.quad_indices {
+            Some(quad_indices) => reduce_parallel(quad_indices, workers, |verts| Quad::new(*verts).min_jacobian(&self.vertices)),
+            None => QualityAcc::IDENTITY,
+        };
+        tri_acc.combine(quad_acc).finish()
+    }
+
+    /// `QualityReport` over this mesh: the Jacobian min/max/avg come from one
+    /// `get_jacobian_stats_parallel` call, then a second, serial loop over the triangles computes
+    /// each min angle for the angle histogram/worst-list. That second half stays serial since it
+    /// needs a single ordered pass to track the worst indices, and meshes large enough for
+    /// `PARALLEL_ELEMENT_THRESHOLD` to matter still walk it in well under the time the Jacobian
+    /// reduction takes.
+    pub fn quality_report(&self, parallel: bool) -> QualityReport {
+        let (min_jacobian, max_jacobian, avg_jacobian) = self.get_jacobian_stats_parallel(parallel);
+
+        let mut angle_histogram = [0usize; ANGLE_HISTOGRAM_BUCKETS];
+        let mut worst: Vec<(usize, f64)> = Vec::new();
+
+        for (i, verts) in self.triangle_indices.iter().enumerate() {
+            let triangle = Triangle::new(*verts, &self.vertices);
+            let angle = triangle.min_angle(&self.vertices);
+
+            let bucket = ((angle / ANGLE_BUCKET_WIDTH_DEGREES) as usize).min(ANGLE_HISTOGRAM_BUCKETS - 1);
+            angle_histogram[bucket] += 1;
+
+            worst.push((i, angle));
+            worst.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            worst.truncate(WORST_ELEMENT_COUNT);
+        }
+
+        QualityReport {
+            min_jacobian,
+            max_jacobian,
+            avg_jacobian,
+            angle_histogram,
+            worst_triangle_indices: worst.into_iter().map(|(i, _)| i).collect(),
+        }
+    }
+
+    /// Maps every vertex through `affine` in place, then refreshes the cached per-triangle and
+    /// per-quad point lists from the transformed vertices rather than transforming each cached
+    /// copy separately - keeps `self.triangles`/`self.quads` a pure function of `self.vertices`
+    /// the same way `new`/`new_with_quads` build them.
+    pub fn transform(&mut self, affine: &Affine2) {
+        for vertex in &mut self.vertices {
+            *vertex = affine.apply(*vertex);
+        }
+
+        for (triangle_points, indices) in self.triangles.iter_mut().zip(&self.triangle_indices) {
+            for (point, &vertex_idx) in triangle_points.iter_mut().zip(indices) {
+                *point = self.vertices[vertex_idx];
+            }
+        }
+
+        if let (Some(quad_points), Some(quad_indices)) = (&mut self.quads, &self.quad_indices) {
+            for (points, indices) in quad_points.iter_mut().zip(quad_indices) {
+                for (point, &vertex_idx) in points.iter_mut().zip(indices) {
+                    *point = self.vertices[vertex_idx];
+                }
+            }
+        }
+    }
+
     pub fn new_with_quads(vertices: Vec<Point>, triangles: Vec<Triangle>, quads: Vec<Quad>) -> Self {
         let triangle_indices: Vec<[usize; 3]> = triangles.iter().map(|t| t.vertices).collect();
         let triangle_points: Vec<Vec<Point>> = triangles.iter().map(|t| {
@@ -1,10 +1,475 @@
-use crate::geometry::{Point, Triangle};
+use crate::geometry::{Edge, Point, SizingField, Triangle};
+use crate::spatial_index::{Aabb, CircumcircleIndex};
 use std::collections::{HashMap, HashSet};
 
+fn orient2d(a: &Point, b: &Point, c: &Point) -> f64 {
+    crate::predicates::orient2d(a.x, a.y, b.x, b.y, c.x, c.y)
+}
+
+fn in_circle(a: &Point, b: &Point, c: &Point, d: &Point) -> bool {
+    let det = crate::predicates::in_circle(a.x, a.y, b.x, b.y, c.x, c.y, d.x, d.y);
+
+    if orient2d(a, b, c) > 0.0 {
+        det > 0.0
+    } else {
+        det < 0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IncTriangle {
+    vertices: [usize; 3],
+}
+
+/// Maintains Delaunay triangles with an undirected edge -> (triangle, triangle) adjacency map,
+/// so a single point insertion only touches the local cavity instead of rebuilding from scratch.
+struct IncrementalTriangulation {
+    points: Vec<Point>,
+    triangles: Vec<Option<IncTriangle>>,
+    adjacency: HashMap<(usize, usize), (Option<usize>, Option<usize>)>,
+    last_triangle: usize,
+    /// Bulk-loaded R-tree over live triangles' circumcircle bboxes, so `insert_point`'s fallback
+    /// seed search - used whenever `locate`'s walk can't find a containing triangle, e.g. the
+    /// very first insertion into a bare seed triangulation - only runs the exact in-circle test
+    /// against triangles whose circumcircle could plausibly contain the point, instead of every
+    /// live triangle. `None` below `CIRCUMCIRCLE_INDEX_THRESHOLD` points, matching
+    /// `DelaunayTriangulator`'s own small-input skip.
+    circumcircle_index: Option<CircumcircleIndex>,
+}
+
+impl IncrementalTriangulation {
+    /// Below this many points, the exact linear-scan fallback `insert_point` already falls back
+    /// to is cheap enough that building and maintaining `circumcircle_index` would cost more than
+    /// it saves.
+    const CIRCUMCIRCLE_INDEX_THRESHOLD: usize = 64;
+
+    fn edge_key(a: usize, b: usize) -> (usize, usize) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    fn build(points: Vec<Point>, seed_triangles: Vec<[usize; 3]>) -> Self {
+        let mut triangulation = Self {
+            points,
+            triangles: Vec::new(),
+            adjacency: HashMap::new(),
+            last_triangle: 0,
+            circumcircle_index: None,
+        };
+        for vertices in seed_triangles {
+            triangulation.last_triangle = triangulation.push_triangle(vertices);
+        }
+        triangulation
+    }
+
+    fn push_triangle(&mut self, vertices: [usize; 3]) -> usize {
+        let id = self.triangles.len();
+        self.triangles.push(Some(IncTriangle { vertices }));
+        for i in 0..3 {
+            let a = vertices[i];
+            let b = vertices[(i + 1) % 3];
+            let entry = self.adjacency.entry(Self::edge_key(a, b)).or_insert((None, None));
+            if entry.0.is_none() {
+                entry.0 = Some(id);
+            } else {
+                entry.1 = Some(id);
+            }
+        }
+
+        if self.points.len() >= Self::CIRCUMCIRCLE_INDEX_THRESHOLD {
+            let triangle = Triangle::new(vertices[0], vertices[1], vertices[2]);
+            if let Some(center) = triangle.circumcenter(&self.points) {
+                let bbox = Aabb::of_circle(center, triangle.circumradius(&self.points).powi(2));
+                match &mut self.circumcircle_index {
+                    Some(index) => index.insert(id, bbox),
+                    None => self.circumcircle_index = Some(CircumcircleIndex::build(vec![(id, bbox)])),
+                }
+            }
+        }
+
+        id
+    }
+
+    fn remove_triangle(&mut self, id: usize) {
+        if let Some(tri) = self.triangles[id].take() {
+            if let Some(index) = &mut self.circumcircle_index {
+                index.remove(id);
+            }
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if let Some(entry) = self.adjacency.get_mut(&Self::edge_key(a, b)) {
+                    if entry.0 == Some(id) {
+                        entry.0 = entry.1.take();
+                    } else if entry.1 == Some(id) {
+                        entry.1 = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every candidate triangle id whose circumcircle bbox contains `point`, per
+    /// `circumcircle_index` - a superset `insert_point`'s fallback still confirms with the exact
+    /// in-circle predicate, or `None` if the index isn't built (too few points).
+    fn find_seed_via_circumcircle_index(&self, point: &Point) -> Option<usize> {
+        let index = self.circumcircle_index.as_ref()?;
+        index.candidates_containing(point).into_iter()
+            .find(|&id| self.triangles[id].map_or(false, |tri| {
+                in_circle(&self.points[tri.vertices[0]], &self.points[tri.vertices[1]], &self.points[tri.vertices[2]], point)
+            }))
+    }
+
+    fn neighbor_across(&self, id: usize, a: usize, b: usize) -> Option<usize> {
+        self.adjacency.get(&Self::edge_key(a, b)).and_then(|&(t0, t1)| {
+            if t0 == Some(id) {
+                t1
+            } else if t1 == Some(id) {
+                t0
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Topological neighbor query: the triangle across `tri`'s `edge_index`-th edge (0 = v0-v1,
+    /// 1 = v1-v2, 2 = v2-v0), or `None` if that edge is on the border.
+    pub(crate) fn neighbor(&self, tri: usize, edge_index: usize) -> Option<usize> {
+        let vertices = self.triangles[tri]?.vertices;
+        let a = vertices[edge_index % 3];
+        let b = vertices[(edge_index + 1) % 3];
+        self.neighbor_across(tri, a, b)
+    }
+
+    /// Every edge owned by exactly one live triangle - the current domain boundary (outer hull
+    /// plus any unfilled hole loops).
+    pub(crate) fn boundary_edges(&self) -> Vec<Edge> {
+        self.adjacency.iter()
+            .filter(|&(_, &(t0, t1))| t0.is_some() != t1.is_some())
+            .map(|(&(a, b), _)| Edge::new(a, b))
+            .collect()
+    }
+
+    /// Walks from the last-inserted triangle toward `p`, crossing whichever edge `p` lies
+    /// outside of, until it lands in the triangle that contains `p` (or falls off the mesh).
+    fn locate(&self, p: &Point) -> Option<usize> {
+        let mut current = if self.triangles.get(self.last_triangle).map_or(false, |t| t.is_some()) {
+            self.last_triangle
+        } else {
+            self.triangles.iter().position(|t| t.is_some())?
+        };
+
+        for _ in 0..self.triangles.len() + 1 {
+            let tri = self.triangles[current]?;
+            let [a, b, c] = tri.vertices;
+            let edges = [(a, b), (b, c), (c, a)];
+            let mut moved = false;
+
+            for (ea, eb) in edges {
+                if orient2d(&self.points[ea], &self.points[eb], p) < 0.0 {
+                    match self.neighbor_across(current, ea, eb) {
+                        Some(next) => {
+                            current = next;
+                            moved = true;
+                            break;
+                        }
+                        None => return None,
+                    }
+                }
+            }
+
+            if !moved {
+                return Some(current);
+            }
+        }
+
+        None
+    }
+
+    /// Inserts `p`, retriangulating only the cavity of triangles whose circumcircle contains it.
+    /// Rejects the insertion outright (leaving the triangulation untouched) if `p` would be
+    /// exactly collinear with any cavity-boundary edge, since that would close the cavity with a
+    /// zero-area sliver instead of a real triangle - this is what guards Ruppert refinement
+    /// against manufacturing degenerate triangles when a circumcenter lands on an existing edge.
+    fn insert_point(&mut self, p: Point) -> bool {
+        let start = match self.locate(&p)
+            .or_else(|| self.find_seed_via_circumcircle_index(&p))
+            .or_else(|| self.triangles.iter().position(|t| t.map_or(false, |tri| {
+                in_circle(&self.points[tri.vertices[0]], &self.points[tri.vertices[1]], &self.points[tri.vertices[2]], &p)
+            })))
+        {
+            Some(t) => t,
+            None => return false,
+        };
+
+        let point_idx = self.points.len();
+
+        let mut cavity = HashSet::new();
+        let mut stack = vec![start];
+        cavity.insert(start);
+
+        while let Some(id) = stack.pop() {
+            let tri = self.triangles[id].unwrap();
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                if let Some(neighbor) = self.neighbor_across(id, a, b) {
+                    if cavity.contains(&neighbor) {
+                        continue;
+                    }
+                    if let Some(ntri) = self.triangles[neighbor] {
+                        let [na, nb, nc] = ntri.vertices;
+                        if in_circle(&self.points[na], &self.points[nb], &self.points[nc], &p) {
+                            cavity.insert(neighbor);
+                            stack.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut boundary = Vec::new();
+        for &id in &cavity {
+            let tri = self.triangles[id].unwrap();
+            for i in 0..3 {
+                let a = tri.vertices[i];
+                let b = tri.vertices[(i + 1) % 3];
+                let outside = match self.neighbor_across(id, a, b) {
+                    Some(neighbor) => !cavity.contains(&neighbor),
+                    None => true,
+                };
+                if outside {
+                    boundary.push((a, b));
+                }
+            }
+        }
+
+        let degenerate = boundary.iter().any(|&(a, b)| {
+            orient2d(&self.points[a], &self.points[b], &p).abs() < 1e-9
+        });
+        if degenerate {
+            return false;
+        }
+
+        self.points.push(p);
+
+        for &id in &cavity {
+            self.remove_triangle(id);
+        }
+
+        for (a, b) in boundary {
+            self.last_triangle = self.push_triangle([a, b, point_idx]);
+        }
+
+        true
+    }
+
+    fn parts(&self) -> (Vec<Point>, Vec<[usize; 3]>) {
+        let triangles = self.triangles.iter().filter_map(|t| t.map(|tri| tri.vertices)).collect();
+        (self.points.clone(), triangles)
+    }
+
+    fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Current vertex positions, including every point inserted since `build` - live, with no
+    /// per-call copy, so a refinement loop can read it on every iteration without resyncing
+    /// `MeshCore`'s own `points`/`triangles` each time.
+    fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    fn live_triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.triangles.iter().filter_map(|t| t.map(|tri| tri.vertices))
+    }
+}
+
+/// Vertex-order-independent identity for a triangle, used to remember which ones refinement has
+/// already tried and rejected so it doesn't retry the same unfixable triangle forever.
+fn canonical_triangle(vertices: [usize; 3]) -> [usize; 3] {
+    let mut v = vertices;
+    v.sort_unstable();
+    v
+}
+
+fn segment_midpoint(points: &[Point], a: usize, b: usize) -> Point {
+    let pa = points[a];
+    let pb = points[b];
+    Point::new((pa.x + pb.x) / 2.0, (pa.y + pb.y) / 2.0)
+}
+
+/// A point encroaches a subsegment if it lies inside the subsegment's diametral circle
+/// (centered at the midpoint, radius half the segment length).
+fn encroaches_segment(points: &[Point], a: usize, b: usize, p: &Point) -> bool {
+    let center = segment_midpoint(points, a, b);
+    let radius = points[a].distance_to(&points[b]) / 2.0;
+    center.distance_to(p) < radius - 1e-9
+}
+
+fn segment_is_encroached(points: &[Point], a: usize, b: usize) -> bool {
+    points.iter().enumerate().any(|(i, p)| i != a && i != b && encroaches_segment(points, a, b, p))
+}
+
+/// The single worst triangle violating `metric`'s `threshold` (smallest min-angle below it, or
+/// largest aspect ratio above it), or `None` if every triangle already passes. Takes the point
+/// list and a triangle-vertex iterator directly rather than a `MeshCore` so `refine_mesh` can feed
+/// it an `IncrementalTriangulation`'s live state without first copying it out.
+///
+/// When `sizing` is set, bad triangles are ranked by how oversized each one is
+/// relative to its *local* target (circumradius divided by the sizing field sampled at its
+/// centroid) instead of by raw quality - so refinement chases the triangle furthest from the
+/// locally-appropriate size first, not just the globally worst angle/aspect ratio.
+fn find_worst_triangle_among(
+    points: &[Point],
+    triangles: impl Iterator<Item = [usize; 3]>,
+    metric: &str,
+    threshold: f64,
+    sizing: Option<&SizingField>,
+) -> Option<Triangle> {
+    let mut worst_triangle = None;
+    let mut worst_score = if sizing.is_some() || metric != "angle" { 0.0 } else { 180.0 };
+
+    for vertices in triangles {
+        let triangle = Triangle::new(vertices[0], vertices[1], vertices[2]);
+        let quality = match metric {
+            "angle" => triangle.min_angle(points),
+            "aspect" => triangle.aspect_ratio(points),
+            _ => continue,
+        };
+
+        let is_bad = match metric {
+            "angle" => quality < threshold,
+            "aspect" => quality > threshold,
+            _ => false,
+        };
+
+        if !is_bad {
+            continue;
+        }
+
+        if let Some(field) = sizing {
+            let centroid = triangle.center(points);
+            let target = field.sample(centroid).max(1e-9);
+            let oversize = triangle.circumradius(points) / target;
+            if oversize > worst_score {
+                worst_score = oversize;
+                worst_triangle = Some(triangle);
+            }
+            continue;
+        }
+
+        let is_worse = match metric {
+            "angle" => quality < worst_score,
+            "aspect" => quality > worst_score,
+            _ => false,
+        };
+
+        if is_worse {
+            worst_score = quality;
+            worst_triangle = Some(triangle);
+        }
+    }
+
+    worst_triangle
+}
+
+fn average_edge_length(polygon: &[Point]) -> f64 {
+    let n = polygon.len();
+    if n == 0 {
+        return 1.0;
+    }
+    let total: f64 = (0..n).map(|i| polygon[i].distance_to(&polygon[(i + 1) % n])).sum();
+    (total / n as f64).max(1e-9)
+}
+
+/// Buckets boundary edges into fixed-size grid cells covering the polygon's bounding box, so
+/// a point-in-polygon ray cast only has to consider the edges in the point's row instead of
+/// every edge in the polygon. Purely a broad-phase filter; `contains` gives exact results.
+struct BoundaryGrid {
+    cell_size: f64,
+    min_y: f64,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl BoundaryGrid {
+    fn build(polygon: &[Point], cell_size: f64) -> Self {
+        let cell_size = cell_size.max(1e-9);
+        let mut min_x = f64::INFINITY;
+        let mut min_y = f64::INFINITY;
+        for p in polygon {
+            min_x = min_x.min(p.x);
+            min_y = min_y.min(p.y);
+        }
+
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        let n = polygon.len();
+
+        for i in 0..n {
+            let a = polygon[i];
+            let b = polygon[(i + 1) % n];
+
+            let lo_x = ((a.x.min(b.x) - min_x) / cell_size).floor() as i32;
+            let hi_x = ((a.x.max(b.x) - min_x) / cell_size).floor() as i32;
+            let lo_y = ((a.y.min(b.y) - min_y) / cell_size).floor() as i32;
+            let hi_y = ((a.y.max(b.y) - min_y) / cell_size).floor() as i32;
+
+            for cx in lo_x..=hi_x {
+                for cy in lo_y..=hi_y {
+                    cells.entry((cx, cy)).or_insert_with(Vec::new).push(i);
+                }
+            }
+        }
+
+        Self { cell_size, min_y, cells }
+    }
+
+    fn row_of(&self, y: f64) -> i32 {
+        ((y - self.min_y) / self.cell_size).floor() as i32
+    }
+
+    fn contains(&self, polygon: &[Point], point: &Point) -> bool {
+        let row = self.row_of(point.y);
+
+        let mut edges_in_row = HashSet::new();
+        for (&(_, cy), edges) in self.cells.iter() {
+            if cy == row {
+                edges_in_row.extend(edges.iter().cloned());
+            }
+        }
+
+        if edges_in_row.is_empty() {
+            return false;
+        }
+
+        let x = point.x;
+        let y = point.y;
+        let mut inside = false;
+        let n = polygon.len();
+
+        for i in edges_in_row {
+            let pi = polygon[i];
+            let pj = polygon[(i + 1) % n];
+
+            if ((pi.y > y) != (pj.y > y)) && (x < (pj.x - pi.x) * (y - pi.y) / (pj.y - pi.y) + pi.x) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+}
+
 pub struct MeshCore {
     pub points: Vec<Point>,
     pub triangles: Vec<Triangle>,
     pub boundary_points: HashSet<usize>,
+    /// Interior obstacle loops (bolt holes, pylons, ...) that `generate_mesh` excludes from the
+    /// meshed region: a triangle is kept only if its centroid is inside the outer polygon and
+    /// outside every one of these. Empty for a plain simply-connected domain.
+    pub holes: Vec<Vec<Point>>,
+    /// Spatially-varying target element size. When set, `generate_mesh` grades element size by
+    /// sampling this field instead of using a single scalar `density` everywhere.
+    pub sizing: Option<SizingField>,
 }
 
 impl MeshCore {
@@ -13,6 +478,8 @@ impl MeshCore {
             points: Vec::new(),
             triangles: Vec::new(),
             boundary_points: HashSet::new(),
+            holes: Vec::new(),
+            sizing: None,
         }
     }
 
@@ -20,11 +487,13 @@ impl MeshCore {
         self.points.clear();
         self.triangles.clear();
         self.boundary_points.clear();
+        self.holes.clear();
+        self.sizing = None;
     }
 
     pub fn add_polygon(&mut self, polygon_points: &[f64]) {
         self.clear();
-        
+
         for i in (0..polygon_points.len()).step_by(2) {
             if i + 1 < polygon_points.len() {
                 let point = Point::new(polygon_points[i], polygon_points[i + 1]);
@@ -42,6 +511,13 @@ impl MeshCore {
         }
     }
 
+    /// Like `add_polygon_from_points`, but also records `holes` - interior loops `generate_mesh`
+    /// will treat as obstacles rather than meshed area.
+    pub fn add_polygon_with_holes(&mut self, polygon_points: &[Point], holes: &[Vec<Point>]) {
+        self.add_polygon_from_points(polygon_points);
+        self.holes = holes.to_vec();
+    }
+
     fn is_point_in_polygon(&self, point: &Point, polygon: &[Point]) -> bool {
         let x = point.x;
         let y = point.y;
@@ -69,26 +545,56 @@ impl MeshCore {
         }
 
         let polygon = self.points.clone();
-        self.densify_boundary(density);
-        self.add_interior_points(density, &polygon);
-        self.triangulate(&polygon);
+        let holes = self.holes.clone();
+        let rings = self.densify_boundary(density);
+        self.add_interior_points(density, &polygon, &holes);
+        self.triangulate(&polygon, &holes);
+        self.recover_constrained_edges(&rings);
 
         true
     }
 
-    fn densify_boundary(&mut self, density: f64) {
-        let original_count = self.points.len();
+    /// Densifies the outer boundary and every hole independently (so an edge never spans across
+    /// two different loops), then concatenates them back into `self.points` as outer-ring points
+    /// followed by each hole's points in turn. Returns each ring's index range in the result, for
+    /// `recover_constrained_edges` to rebuild the required boundary/hole segments from.
+    fn densify_boundary(&mut self, density: f64) -> Vec<std::ops::Range<usize>> {
+        let sizing = self.sizing.clone();
+        let outer = std::mem::take(&mut self.points);
+        let mut densified = Self::densify_ring(&outer, density, sizing.as_ref());
+        let mut rings = vec![0..densified.len()];
+
+        for hole in &self.holes {
+            let start = densified.len();
+            densified.extend(Self::densify_ring(hole, density, sizing.as_ref()));
+            rings.push(start..densified.len());
+        }
+
+        self.points = densified;
+        self.boundary_points.clear();
+        for i in 0..self.points.len() {
+            self.boundary_points.insert(i);
+        }
+        rings
+    }
+
+    /// Subdivides each edge of `ring` into segments no longer than the local target size -
+    /// `sizing.sample(midpoint)` when a sizing field is set, else the flat `density` everywhere.
+    fn densify_ring(ring: &[Point], density: f64, sizing: Option<&SizingField>) -> Vec<Point> {
+        let n = ring.len();
         let mut new_points = Vec::new();
-        
-        for i in 0..original_count {
-            let current = self.points[i];
-            let next = self.points[(i + 1) % original_count];
-            
+
+        for i in 0..n {
+            let current = ring[i];
+            let next = ring[(i + 1) % n];
+
             let edge_length = current.distance_to(&next);
-            let num_segments = (edge_length / density).ceil() as usize;
-            
+            let midpoint = Point::new((current.x + next.x) / 2.0, (current.y + next.y) / 2.0);
+            let h = sizing.map(|s| s.sample(midpoint)).unwrap_or(density);
+            let num_segments = (edge_length / h).ceil() as usize;
+
             new_points.push(current);
-            
+
             if num_segments > 1 {
                 for j in 1..num_segments {
                     let t = j as f64 / num_segments as f64;
@@ -98,15 +604,14 @@ impl MeshCore {
                 }
             }
         }
-        
-        self.points = new_points;
-        self.boundary_points.clear();
-        for i in 0..self.points.len() {
-            self.boundary_points.insert(i);
-        }
+
+        new_points
     }
 
-    fn add_interior_points(&mut self, density: f64, polygon: &[Point]) {
+    /// Scans the polygon's bounding box for interior Steiner points, advancing the scan by the
+    /// local target size and rejecting candidates closer than that to an already-placed point,
+    /// when a sizing field is set - otherwise the original fixed-`density` grid scan.
+    fn add_interior_points(&mut self, density: f64, polygon: &[Point], holes: &[Vec<Point>]) {
         let mut min_x = f64::INFINITY;
         let mut min_y = f64::INFINITY;
         let mut max_x = f64::NEG_INFINITY;
@@ -119,21 +624,31 @@ impl MeshCore {
             max_y = max_y.max(point.y);
         }
 
+        let grid = BoundaryGrid::build(polygon, density);
+        let hole_grids: Vec<BoundaryGrid> = holes.iter().map(|hole| BoundaryGrid::build(hole, density)).collect();
+        let sizing = self.sizing.clone();
+
         let mut x = min_x;
         while x < max_x {
+            let column_h = sizing.as_ref().map(|s| s.sample(Point::new(x, min_y))).unwrap_or(density);
+
             let mut y = min_y;
             while y < max_y {
                 let point = Point::new(x, y);
-                if self.is_point_in_polygon(&point, polygon) {
+                let h = sizing.as_ref().map(|s| s.sample(point)).unwrap_or(density);
+                let inside_hole = hole_grids.iter().zip(holes).any(|(g, hole)| g.contains(hole, &point));
+                let too_close = sizing.is_some() && self.points.iter().any(|p| p.distance_to(&point) < h);
+
+                if grid.contains(polygon, &point) && !inside_hole && !too_close {
                     self.points.push(point);
                 }
-                y += density;
+                y += h;
             }
-            x += density;
+            x += column_h.max(1e-9);
         }
     }
 
-    fn triangulate(&mut self, polygon: &[Point]) {
+    fn triangulate(&mut self, polygon: &[Point], holes: &[Vec<Point>]) {
         if self.points.len() < 3 {
             return;
         }
@@ -143,86 +658,220 @@ impl MeshCore {
             .collect();
 
         let triangulation = delaunator::triangulate(&delaunay_points);
-        
+
         self.triangles.clear();
-        
+
+        let grid = BoundaryGrid::build(polygon, average_edge_length(polygon));
+        let hole_grids: Vec<BoundaryGrid> = holes.iter()
+            .map(|hole| BoundaryGrid::build(hole, average_edge_length(hole)))
+            .collect();
+
         for i in (0..triangulation.triangles.len()).step_by(3) {
             let tri = Triangle::new(
                 triangulation.triangles[i],
                 triangulation.triangles[i + 1],
                 triangulation.triangles[i + 2],
             );
-            
+
+            // `delaunator` can hand back a zero-area triangle when three of its input points are
+            // exactly collinear (e.g. consecutive points densified along the same straight
+            // boundary edge); keeping one would seed refine_mesh with an unfixable 0-degree
+            // triangle, since a collinear triangle has no circumcenter to refine it with.
+            let [p1, p2, p3] = tri.get_points(&self.points);
+            if orient2d(p1, p2, p3).abs() < 1e-10 {
+                continue;
+            }
+
             let center = tri.center(&self.points);
-            if self.is_point_in_polygon(&center, polygon) {
+            let inside_hole = hole_grids.iter().zip(holes).any(|(g, h)| g.contains(h, &center));
+            if grid.contains(polygon, &center) && !inside_hole {
                 self.triangles.push(tri);
             }
         }
     }
 
+    /// True if some live triangle has `a`-`b` (in either winding) as one of its edges.
+    fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.triangles.iter().any(|t| {
+            let v = t.indices;
+            (v[0] == a && v[1] == b) || (v[1] == a && v[2] == b) || (v[2] == a && v[0] == b)
+                || (v[0] == b && v[1] == a) || (v[1] == b && v[2] == a) || (v[2] == b && v[0] == a)
+        })
+    }
+
+    /// Finds a triangle edge that crosses the open segment `a`-`b` and, if flipping its diagonal
+    /// would keep both resulting triangles non-degenerate, performs the flip. Returns whether a
+    /// flip was made; repeated calls converge `a`-`b` onto the triangulation by shrinking the set
+    /// of edges it crosses one quad at a time (Lawson's "flip algorithm" applied to a required
+    /// segment instead of an in-circle violation).
+    fn flip_one_crossing_edge(&mut self, a: usize, b: usize) -> bool {
+        let mut edge_triangles: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (ti, triangle) in self.triangles.iter().enumerate() {
+            for i in 0..3 {
+                let p = triangle.indices[i];
+                let q = triangle.indices[(i + 1) % 3];
+                let key = if p < q { (p, q) } else { (q, p) };
+                edge_triangles.entry(key).or_insert_with(Vec::new).push(ti);
+            }
+        }
+
+        let (pa, pb) = (self.points[a], self.points[b]);
+
+        for (&(p, q), tris) in edge_triangles.iter() {
+            if tris.len() != 2 || p == a || p == b || q == a || q == b {
+                continue;
+            }
+            if !crate::geometry::segments_properly_intersect(pa, pb, self.points[p], self.points[q]) {
+                continue;
+            }
+
+            let (t0, t1) = (tris[0], tris[1]);
+            let opp0 = self.triangles[t0].indices.iter().cloned().find(|&v| v != p && v != q);
+            let opp1 = self.triangles[t1].indices.iter().cloned().find(|&v| v != p && v != q);
+
+            if let (Some(r1), Some(r2)) = (opp0, opp1) {
+                let new0 = [p, r2, r1];
+                let new1 = [q, r1, r2];
+                let area0 = orient2d(&self.points[new0[0]], &self.points[new0[1]], &self.points[new0[2]]);
+                let area1 = orient2d(&self.points[new1[0]], &self.points[new1[1]], &self.points[new1[2]]);
+                if area0 <= 0.0 || area1 <= 0.0 {
+                    continue;
+                }
+
+                self.triangles[t0] = Triangle::new(new0[0], new0[1], new0[2]);
+                self.triangles[t1] = Triangle::new(new1[0], new1[1], new1[2]);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Recovers every boundary/hole ring edge that the unconstrained Delaunay triangulation may
+    /// have skipped over, by repeatedly flipping a crossing edge until each one is present (or a
+    /// generous flip budget runs out, for pathological/degenerate input).
+    fn recover_constrained_edges(&mut self, rings: &[std::ops::Range<usize>]) {
+        let mut required_edges: Vec<(usize, usize)> = Vec::new();
+        for ring in rings {
+            let n = ring.len();
+            if n < 2 {
+                continue;
+            }
+            for i in 0..n {
+                let a = ring.start + i;
+                let b = ring.start + (i + 1) % n;
+                required_edges.push((a, b));
+            }
+        }
+
+        let flip_budget = (self.triangles.len().max(16)) * 4;
+
+        for (a, b) in required_edges {
+            let mut attempts = 0;
+            while !self.has_edge(a, b) && attempts < flip_budget {
+                attempts += 1;
+                if !self.flip_one_crossing_edge(a, b) {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Ruppert-style refinement: drains a queue of encroached boundary subsegments (split at
+    /// their midpoint) before ever inserting a skinny triangle's circumcenter, and defers to
+    /// splitting instead of inserting whenever that circumcenter would itself encroach a
+    /// subsegment. A triangle whose circumcenter can't be inserted - because it falls outside the
+    /// polygon, is itself already degenerate, or would close `IncrementalTriangulation`'s cavity
+    /// with a sliver - is recorded in `rejected` and skipped from then on, so refinement keeps
+    /// making progress on every other skinny triangle instead of stalling the whole pass.
     pub fn refine_mesh(&mut self, metric: &str, threshold: f64, max_iterations: usize) -> usize {
-        let polygon: Vec<Point> = self.boundary_points.iter()
-            .map(|&i| self.points[i])
+        let boundary_len = self.boundary_points.len();
+        let polygon: Vec<Point> = (0..boundary_len).map(|i| self.points[i]).collect();
+
+        let mut subsegments: Vec<(usize, usize)> = (0..boundary_len)
+            .map(|i| (i, (i + 1) % boundary_len))
             .collect();
 
+        let seed_triangles: Vec<[usize; 3]> = self.triangles.iter().map(|t| t.indices).collect();
+        let mut incremental = IncrementalTriangulation::build(self.points.clone(), seed_triangles);
+
+        let mut rejected: HashSet<[usize; 3]> = HashSet::new();
         let mut iterations = 0;
-        
+
         for _ in 0..max_iterations {
-            let worst_triangle = self.find_worst_triangle(metric, threshold);
-            
-            if worst_triangle.is_none() {
-                break;
-            }
-            
-            let triangle = worst_triangle.unwrap();
-            if let Some(circumcenter) = triangle.circumcenter(&self.points) {
-                if self.is_point_in_polygon(&circumcenter, &polygon) {
-                    self.points.push(circumcenter);
-                    self.triangulate(&polygon);
-                    iterations += 1;
-                } else {
+            let encroached = subsegments.iter()
+                .position(|&(a, b)| segment_is_encroached(incremental.points(), a, b));
+
+            if let Some(idx) = encroached {
+                let (a, b) = subsegments[idx];
+                let midpoint = segment_midpoint(incremental.points(), a, b);
+                let mid_idx = incremental.point_count();
+
+                if !incremental.insert_point(midpoint) {
                     break;
                 }
+
+                subsegments[idx] = (a, mid_idx);
+                subsegments.push((mid_idx, b));
             } else {
-                break;
-            }
-        }
-        
-        iterations
-    }
+                let worst_triangle = find_worst_triangle_among(
+                    incremental.points(),
+                    incremental.live_triangles().filter(|&v| !rejected.contains(&canonical_triangle(v))),
+                    metric,
+                    threshold,
+                    self.sizing.as_ref(),
+                );
+                let triangle = match worst_triangle {
+                    Some(t) => t,
+                    None => break,
+                };
 
-    fn find_worst_triangle(&self, metric: &str, threshold: f64) -> Option<Triangle> {
-        let mut worst_triangle = None;
-        let mut worst_quality = if metric == "angle" { 180.0 } else { 0.0 };
-
-        for triangle in &self.triangles {
-            let quality = match metric {
-                "angle" => triangle.min_angle(&self.points),
-                "aspect" => triangle.aspect_ratio(&self.points),
-                _ => continue,
-            };
-
-            let is_bad = match metric {
-                "angle" => quality < threshold,
-                "aspect" => quality > threshold,
-                _ => false,
-            };
-
-            if is_bad {
-                let is_worse = match metric {
-                    "angle" => quality < worst_quality,
-                    "aspect" => quality > worst_quality,
-                    _ => false,
+                let circumcenter = match triangle.circumcenter(incremental.points()) {
+                    Some(c) => c,
+                    None => {
+                        rejected.insert(canonical_triangle(triangle.indices));
+                        continue;
+                    }
                 };
 
-                if is_worse {
-                    worst_quality = quality;
-                    worst_triangle = Some(triangle.clone());
+                if !self.is_point_in_polygon(&circumcenter, &polygon) {
+                    rejected.insert(canonical_triangle(triangle.indices));
+                    continue;
+                }
+
+                let encroaching: Vec<usize> = subsegments.iter().enumerate()
+                    .filter(|&(_, &(a, b))| encroaches_segment(incremental.points(), a, b, &circumcenter))
+                    .map(|(i, _)| i)
+                    .collect();
+
+                if encroaching.is_empty() {
+                    if !incremental.insert_point(circumcenter) {
+                        rejected.insert(canonical_triangle(triangle.indices));
+                        continue;
+                    }
+                } else {
+                    for idx in encroaching {
+                        let (a, b) = subsegments[idx];
+                        let midpoint = segment_midpoint(incremental.points(), a, b);
+                        let mid_idx = incremental.point_count();
+                        if incremental.insert_point(midpoint) {
+                            subsegments[idx] = (a, mid_idx);
+                            subsegments.push((mid_idx, b));
+                        }
+                    }
                 }
             }
+
+            iterations += 1;
         }
 
-        worst_triangle
+        let (points, triangle_indices) = incremental.parts();
+        self.points = points;
+        self.triangles = triangle_indices.into_iter()
+            .map(|v| Triangle::new(v[0], v[1], v[2]))
+            .collect();
+
+        iterations
     }
 
     pub fn smooth_mesh(&mut self, iterations: usize) -> bool {
@@ -272,7 +921,7 @@ impl MeshCore {
             }
 
             self.points = new_points;
-            self.triangulate(&polygon);
+            self.legalize_edges();
 
             if moved_count == 0 {
                 break;
@@ -282,6 +931,91 @@ impl MeshCore {
         true
     }
 
+    /// Flips any edge shared by two triangles whose opposite vertex lies inside the other's
+    /// circumcircle, repeating until the mesh is locally Delaunay again after a smoothing pass.
+    /// Runs over an `IncrementalTriangulation` so each candidate edge's other triangle is a
+    /// direct `neighbor` lookup, instead of rebuilding an edge-to-triangles map from scratch on
+    /// every flip the way this used to.
+    fn legalize_edges(&mut self) {
+        let seed_triangles: Vec<[usize; 3]> = self.triangles.iter().map(|t| t.indices).collect();
+        let mut incremental = IncrementalTriangulation::build(self.points.clone(), seed_triangles);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            let tri_count = incremental.triangles.len();
+            for tri_id in 0..tri_count {
+                let tri = match incremental.triangles[tri_id] {
+                    Some(tri) => tri,
+                    None => continue,
+                };
+
+                let mut flipped = false;
+                for edge_index in 0..3 {
+                    let other_id = match incremental.neighbor(tri_id, edge_index) {
+                        Some(id) => id,
+                        None => continue,
+                    };
+                    let other = match incremental.triangles[other_id] {
+                        Some(other) => other,
+                        None => continue,
+                    };
+
+                    let a = tri.vertices[edge_index];
+                    let b = tri.vertices[(edge_index + 1) % 3];
+                    let c = tri.vertices[(edge_index + 2) % 3];
+                    let d = match other.vertices.iter().find(|&&v| v != a && v != b) {
+                        Some(&d) => d,
+                        None => continue,
+                    };
+
+                    if in_circle(&incremental.points[a], &incremental.points[b], &incremental.points[c], &incremental.points[d]) {
+                        incremental.remove_triangle(tri_id);
+                        incremental.remove_triangle(other_id);
+                        incremental.push_triangle([a, c, d]);
+                        incremental.push_triangle([b, d, c]);
+                        changed = true;
+                        flipped = true;
+                        break;
+                    }
+                }
+
+                if flipped {
+                    break;
+                }
+            }
+        }
+
+        let (_, triangle_indices) = incremental.parts();
+        self.triangles = triangle_indices.into_iter()
+            .map(|v| Triangle::new(v[0], v[1], v[2]))
+            .collect();
+    }
+
+    /// Indices into `self.triangles` of every triangle that owns a domain-boundary edge (one
+    /// shared by no other live triangle), i.e. the outer hull plus the rim of any interior hole.
+    /// Built from the current flat triangle list rather than the incremental structure used by
+    /// `refine_mesh`, so it stays valid after `generate_mesh`/`smooth_mesh` too - but reuses that
+    /// same structure's `boundary_edges` rather than re-deriving boundary-ness from scratch.
+    pub fn boundary_triangles(&self) -> HashSet<usize> {
+        let seed_triangles: Vec<[usize; 3]> = self.triangles.iter().map(|t| t.indices).collect();
+        let incremental = IncrementalTriangulation::build(self.points.clone(), seed_triangles);
+
+        let mut edge_owner: HashMap<(usize, usize), usize> = HashMap::new();
+        for (ti, triangle) in self.triangles.iter().enumerate() {
+            for i in 0..3 {
+                let a = triangle.indices[i];
+                let b = triangle.indices[(i + 1) % 3];
+                edge_owner.insert(IncrementalTriangulation::edge_key(a, b), ti);
+            }
+        }
+
+        incremental.boundary_edges().iter()
+            .filter_map(|edge| edge_owner.get(&IncrementalTriangulation::edge_key(edge.vertices[0], edge.vertices[1])).copied())
+            .collect()
+    }
+
     pub fn get_average_quality(&self, metric: &str) -> f64 {
         if self.triangles.is_empty() {
             return 0.0;
@@ -297,4 +1031,97 @@ impl MeshCore {
 
         total / self.triangles.len() as f64
     }
+
+    /// Same result as `get_average_quality` (up to floating-point summation order), but splits
+    /// `self.triangles` across `std::thread::available_parallelism()` worker threads instead of
+    /// one serial pass. Worthwhile only once there are enough triangles to amortize the thread
+    /// spawn cost, so small/medium meshes (and `parallel: false`) just defer to the serial path.
+    pub fn get_average_quality_parallel(&self, metric: &str, parallel: bool) -> f64 {
+        const PARALLEL_TRIANGLE_THRESHOLD: usize = 10_000;
+
+        if self.triangles.is_empty() {
+            return 0.0;
+        }
+        if !parallel || self.triangles.len() < PARALLEL_TRIANGLE_THRESHOLD {
+            return self.get_average_quality(metric);
+        }
+
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let chunk_size = (self.triangles.len() / workers).max(1);
+        let sum: f64 = std::thread::scope(|scope| {
+            let handles: Vec<_> = self.triangles.chunks(chunk_size).map(|chunk| {
+                scope.spawn(|| {
+                    chunk.iter().fold(0.0, |acc, t| acc + match metric {
+                        "angle" => t.min_angle(&self.points),
+                        "aspect" => t.aspect_ratio(&self.points),
+                        _ => 0.0,
+                    })
+                })
+            }).collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+        });
+
+        sum / self.triangles.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A long, thin rectangle - every boundary corner is a comfortable 90 degrees, well above
+    /// `threshold`, so nothing here caps the achievable minimum angle - seeds `refine_mesh` with
+    /// a coarse triangulation full of skinny starting triangles; Ruppert refinement should drive
+    /// every resulting triangle's minimum angle up to (at least very close to) `threshold`.
+    #[test]
+    fn refine_mesh_guarantees_minimum_angle() {
+        let mut mesher = MeshCore::new();
+        mesher.add_polygon_from_points(&[
+            Point::new(0.0, 0.0),
+            Point::new(20.0, 0.0),
+            Point::new(20.0, 1.0),
+            Point::new(0.0, 1.0),
+        ]);
+
+        assert!(mesher.generate_mesh(2.0));
+        assert!(!mesher.triangles.is_empty());
+
+        let threshold = 20.0;
+        mesher.refine_mesh("angle", threshold, 500);
+
+        let worst_angle = mesher.triangles.iter()
+            .map(|t| t.min_angle(&mesher.points))
+            .fold(f64::INFINITY, f64::min);
+
+        assert!(worst_angle >= threshold - 1.0, "worst angle {worst_angle} fell well short of {threshold}");
+    }
+
+    /// A 10x10 square with a 2x2 hole centered at (5, 5): `generate_mesh` should keep a triangle
+    /// only when its centroid is inside the outer polygon and outside the hole, extending
+    /// `filter_mesh_outside_boundary`'s outer-boundary centroid test with holes.
+    #[test]
+    fn generate_mesh_excludes_hole_interior() {
+        let mut mesher = MeshCore::new();
+        let outer = vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ];
+        let hole = vec![
+            Point::new(4.0, 4.0),
+            Point::new(6.0, 4.0),
+            Point::new(6.0, 6.0),
+            Point::new(4.0, 6.0),
+        ];
+        mesher.add_polygon_with_holes(&outer, &[hole.clone()]);
+
+        assert!(mesher.generate_mesh(1.0));
+        assert!(!mesher.triangles.is_empty());
+
+        for triangle in &mesher.triangles {
+            let centroid = triangle.center(&mesher.points);
+            assert!(!mesher.is_point_in_polygon(&centroid, &hole), "triangle centroid fell inside the hole");
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+use crate::geometry::Point;
+use crate::elements::Triangle;
+use crate::mesh::Mesh;
+use std::collections::HashMap;
+
+/// Parses a Wavefront OBJ document into a `Mesh`, triangulating any face with more than three
+/// vertices via ear clipping. Only `v` and `f` lines are read; normals, texture coordinates, and
+/// other directives are ignored, matching the subset tinyobjloader-style consumers typically need.
+pub fn read_obj(contents: &str) -> Result<Mesh, String> {
+    let mut vertices: Vec<Point> = Vec::new();
+    let mut triangles: Vec<Triangle> = Vec::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.trim().split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let x: f64 = tokens.next()
+                    .ok_or("OBJ 'v' line missing x coordinate")?
+                    .parse().map_err(|_| "OBJ 'v' line has a non-numeric x coordinate".to_string())?;
+                let y: f64 = tokens.next()
+                    .ok_or("OBJ 'v' line missing y coordinate")?
+                    .parse().map_err(|_| "OBJ 'v' line has a non-numeric y coordinate".to_string())?;
+                vertices.push(Point::new(x, y));
+            }
+            Some("f") => {
+                let face: Vec<usize> = tokens
+                    .map(|token| {
+                        let index_str = token.split('/').next().unwrap_or(token);
+                        index_str.parse::<usize>()
+                            .map(|one_based| one_based - 1)
+                            .map_err(|_| format!("OBJ 'f' line has an invalid vertex index: {}", token))
+                    })
+                    .collect::<Result<Vec<usize>, String>>()?;
+
+                if face.len() < 3 {
+                    return Err("OBJ 'f' line has fewer than 3 vertices".to_string());
+                }
+
+                for tri_indices in triangulate_face(&face, &vertices) {
+                    triangles.push(Triangle::new(tri_indices, &vertices));
+                }
+            }
+            _ => {} // comments, normals, texture coords, groups, etc. - not needed for meshing
+        }
+    }
+
+    Ok(Mesh::new(vertices, triangles))
+}
+
+/// Ear-clipping triangulation of a simple polygon face (quad or n-gon): repeatedly locates an
+/// "ear" - a convex vertex whose candidate triangle contains no other face vertex - clips it into
+/// the output, and continues until three vertices remain.
+fn triangulate_face(face: &[usize], points: &[Point]) -> Vec<[usize; 3]> {
+    if face.len() == 3 {
+        return vec![[face[0], face[1], face[2]]];
+    }
+
+    let mut remaining: Vec<usize> = face.to_vec();
+    let mut result = Vec::with_capacity(face.len() - 2);
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let mut ear_idx = 0;
+
+        for i in 0..n {
+            let prev = remaining[(i + n - 1) % n];
+            let curr = remaining[i];
+            let next = remaining[(i + 1) % n];
+
+            if !is_convex(&points[prev], &points[curr], &points[next]) {
+                continue;
+            }
+
+            let is_ear = remaining.iter()
+                .all(|&v| v == prev || v == curr || v == next
+                    || !point_in_triangle(&points[v], &points[prev], &points[curr], &points[next]));
+
+            if is_ear {
+                ear_idx = i;
+                break;
+            }
+            // No convex, empty-triangle vertex found yet - `ear_idx` stays at 0 so a
+            // self-intersecting or degenerate face still makes forward progress instead of
+            // looping forever, at the cost of a possibly malformed triangle there.
+        }
+
+        let n = remaining.len();
+        let prev = remaining[(ear_idx + n - 1) % n];
+        let curr = remaining[ear_idx];
+        let next = remaining[(ear_idx + 1) % n];
+        result.push([prev, curr, next]);
+        remaining.remove(ear_idx);
+    }
+
+    result.push([remaining[0], remaining[1], remaining[2]]);
+    result
+}
+
+fn is_convex(prev: &Point, curr: &Point, next: &Point) -> bool {
+    let cross = (curr.x - prev.x) * (next.y - prev.y) - (curr.y - prev.y) * (next.x - prev.x);
+    cross > 0.0
+}
+
+/// True if `p` lies inside (or on the edge of) triangle `a,b,c`, via the standard three
+/// signed-area tests - `p` is inside iff it's on the same side of all three edges.
+fn point_in_triangle(p: &Point, a: &Point, b: &Point, c: &Point) -> bool {
+    let sign = |p1: &Point, p2: &Point, p3: &Point| {
+        (p1.x - p3.x) * (p2.y - p3.y) - (p2.x - p3.x) * (p1.y - p3.y)
+    };
+
+    let d1 = sign(p, a, b);
+    let d2 = sign(p, b, c);
+    let d3 = sign(p, c, a);
+
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+    !(has_neg && has_pos)
+}
+
+/// Serializes a `Mesh` as a flat (z=0) Wavefront OBJ: one `v x y 0` per vertex and one 1-based
+/// `f i j k` face per triangle, so meshes produced here round-trip through external CAD/slicer
+/// tools and back.
+pub fn write_obj(mesh: &Mesh) -> String {
+    let mut obj = String::new();
+
+    for p in &mesh.vertices {
+        obj.push_str(&format!("v {} {} 0\n", p.x, p.y));
+    }
+
+    for tri in &mesh.triangle_indices {
+        obj.push_str(&format!("f {} {} {}\n", tri[0] + 1, tri[1] + 1, tri[2] + 1));
+    }
+
+    obj
+}
+
+/// Per-facet normal for a flat-shaded STL triangle - the unit cross product of its two edge
+/// vectors, or zero for a degenerate triangle.
+fn facet_normal(a: (f64, f64, f64), b: (f64, f64, f64), c: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (ux, uy, uz) = (b.0 - a.0, b.1 - a.1, b.2 - a.2);
+    let (vx, vy, vz) = (c.0 - a.0, c.1 - a.1, c.2 - a.2);
+    let (nx, ny, nz) = (uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx);
+    let len = (nx * nx + ny * ny + nz * nz).sqrt();
+
+    if len < 1e-12 {
+        (0.0, 0.0, 0.0)
+    } else {
+        (nx / len, ny / len, nz / len)
+    }
+}
+
+fn write_stl_facet(buffer: &mut Vec<u8>, a: (f64, f64, f64), b: (f64, f64, f64), c: (f64, f64, f64)) {
+    let (nx, ny, nz) = facet_normal(a, b, c);
+    buffer.extend_from_slice(&(nx as f32).to_le_bytes());
+    buffer.extend_from_slice(&(ny as f32).to_le_bytes());
+    buffer.extend_from_slice(&(nz as f32).to_le_bytes());
+
+    for v in [a, b, c] {
+        buffer.extend_from_slice(&(v.0 as f32).to_le_bytes());
+        buffer.extend_from_slice(&(v.1 as f32).to_le_bytes());
+        buffer.extend_from_slice(&(v.2 as f32).to_le_bytes());
+    }
+
+    buffer.extend_from_slice(&0u16.to_le_bytes());
+}
+
+/// Extrudes the flat 2D mesh into a prism of `thickness` and writes it as binary STL: each
+/// triangle becomes a reversed-winding bottom facet (z=0) and a top facet (z=`thickness`) for
+/// outward normals, joined by wall quads (split into two triangles) along every boundary edge -
+/// one owned by exactly one triangle, so shared interior edges don't grow spurious internal
+/// walls. `thickness == 0.0` instead emits a flat, zero-volume planar STL.
+pub fn export_to_stl(mesh: &Mesh, thickness: f64) -> Vec<u8> {
+    let mut edge_count: HashMap<(usize, usize), usize> = HashMap::new();
+    for tri in &mesh.triangle_indices {
+        for i in 0..3 {
+            let a = tri[i];
+            let b = tri[(i + 1) % 3];
+            let key = if a < b { (a, b) } else { (b, a) };
+            *edge_count.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    let facet_count: usize = if thickness == 0.0 {
+        mesh.triangle_indices.len()
+    } else {
+        let boundary_edges = edge_count.values().filter(|&&count| count == 1).count();
+        mesh.triangle_indices.len() * 2 + boundary_edges * 2
+    };
+
+    let mut buffer = Vec::with_capacity(84 + facet_count * 50);
+    buffer.extend_from_slice(&[0u8; 80]);
+    buffer.extend_from_slice(&(facet_count as u32).to_le_bytes());
+
+    let at = |idx: usize, z: f64| {
+        let p = mesh.vertices[idx];
+        (p.x, p.y, z)
+    };
+
+    for tri in &mesh.triangle_indices {
+        let [i0, i1, i2] = *tri;
+
+        if thickness == 0.0 {
+            write_stl_facet(&mut buffer, at(i0, 0.0), at(i1, 0.0), at(i2, 0.0));
+            continue;
+        }
+
+        write_stl_facet(&mut buffer, at(i0, 0.0), at(i2, 0.0), at(i1, 0.0));
+        write_stl_facet(&mut buffer, at(i0, thickness), at(i1, thickness), at(i2, thickness));
+
+        for (a, b) in [(i0, i1), (i1, i2), (i2, i0)] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            if edge_count[&key] == 1 {
+                write_stl_facet(&mut buffer, at(a, 0.0), at(b, 0.0), at(b, thickness));
+                write_stl_facet(&mut buffer, at(a, 0.0), at(b, thickness), at(a, thickness));
+            }
+        }
+    }
+
+    buffer
+}
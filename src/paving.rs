@@ -138,6 +138,37 @@ impl PavingMeshGenerator {
         (idx < self.points.len() || self.is_point_inside_polygon(&self.points[idx]))
     }
     
+    /// Writes the mesh as a flat (z=0) Wavefront OBJ: `v x y 0` per point, 1-based `f i j k`
+    /// faces per triangle, and 1-based `f i j k l` faces per paving `Quad`.
+    pub fn export_obj(&self) -> String {
+        let mut obj = String::new();
+
+        for p in &self.points {
+            obj.push_str(&format!("v {} {} 0\n", p.x, p.y));
+        }
+
+        for tri in &self.triangles {
+            obj.push_str(&format!(
+                "f {} {} {}\n",
+                tri.vertices[0] + 1,
+                tri.vertices[1] + 1,
+                tri.vertices[2] + 1,
+            ));
+        }
+
+        for quad in &self.quads {
+            obj.push_str(&format!(
+                "f {} {} {} {}\n",
+                quad.vertices[0] + 1,
+                quad.vertices[1] + 1,
+                quad.vertices[2] + 1,
+                quad.vertices[3] + 1,
+            ));
+        }
+
+        obj
+    }
+
     fn fill_boundary_with_triangles(&mut self, boundary_count: usize) {
         if boundary_count >= 3 {
             for i in 1..boundary_count-1 {
